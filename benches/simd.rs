@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use hcomplex::{Complex, Quaternion, simd::SimdMul};
+
+
+const LEN: usize = 1024;
+
+macro_rules! bench_mul { ($name:ident, $A:ty, $T:ty, $dim:expr) => (
+    fn $name(c: &mut Criterion) {
+        let x: Vec<$A> = (0..2 * LEN).map(|n| {
+            let mut a = [0.0 as $T; $dim];
+            for (k, v) in a.iter_mut().enumerate() {
+                *v = ((n * $dim + k) as $T * 0.37).sin();
+            }
+            // Unit norm keeps long chains of products away from underflow.
+            let norm = a.iter().map(|v| v * v).sum::<$T>().sqrt();
+            a.iter_mut().for_each(|v| *v /= norm);
+            <$A>::from_array(a)
+        }).collect();
+        let (a, b) = x.split_at(LEN);
+        let mut out = vec![<$A>::from_array([0.0 as $T; $dim]); LEN];
+        let mut group = c.benchmark_group(stringify!($name));
+        group.bench_function("generic", |bench| bench.iter(|| {
+            for ((r, &p), &q) in out.iter_mut().zip(black_box(a)).zip(black_box(b)) {
+                *r = p * q;
+            }
+            black_box(&out);
+        }));
+        group.bench_function("simd", |bench| bench.iter(|| {
+            for ((r, &p), &q) in out.iter_mut().zip(black_box(a)).zip(black_box(b)) {
+                *r = p.mul_simd(q);
+            }
+            black_box(&out);
+        }));
+        // Dependent products, e.g. accumulation of rotations, are bound by the latency of a single product.
+        group.bench_function("generic_chain", |bench| bench.iter(|| {
+            black_box(a).iter().fold(b[0], |acc, &p| acc * p)
+        }));
+        group.bench_function("simd_chain", |bench| bench.iter(|| {
+            black_box(a).iter().fold(b[0], |acc, &p| acc.mul_simd(p))
+        }));
+        group.finish();
+    }
+) }
+
+bench_mul!(quaternion_f32, Quaternion<f32>, f32, 4);
+bench_mul!(quaternion_f64, Quaternion<f64>, f64, 4);
+bench_mul!(complex_f32, Complex<f32>, f32, 2);
+
+criterion_group!(benches, quaternion_f32, quaternion_f64, complex_f32);
+criterion_main!(benches);
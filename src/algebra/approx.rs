@@ -1,8 +1,8 @@
-use super::{construct::*};
+use super::{construct::*, gamma::Gamma};
 use approx::*;
 
 
-impl<T: Clone, U> AbsDiffEq for Construct<T, U> where T: AbsDiffEq<Epsilon=T>, U: AbsDiffEq<Epsilon=T> {
+impl<T: Clone, U, S: Gamma> AbsDiffEq for Construct<T, U, S> where T: AbsDiffEq<Epsilon=T>, U: AbsDiffEq<Epsilon=T> {
     type Epsilon = T;
     fn default_epsilon() -> Self::Epsilon {
         T::default_epsilon()
@@ -13,7 +13,7 @@ impl<T: Clone, U> AbsDiffEq for Construct<T, U> where T: AbsDiffEq<Epsilon=T>, U
     }
 }
 
-impl<T: Clone, U> RelativeEq for Construct<T, U> where T: RelativeEq<Epsilon=T>, U: RelativeEq<Epsilon=T> {
+impl<T: Clone, U, S: Gamma> RelativeEq for Construct<T, U, S> where T: RelativeEq<Epsilon=T>, U: RelativeEq<Epsilon=T> {
     fn default_max_relative() -> Self::Epsilon {
         T::default_max_relative()
     }
@@ -23,10 +23,11 @@ impl<T: Clone, U> RelativeEq for Construct<T, U> where T: RelativeEq<Epsilon=T>,
     }
 }
 
-impl<T: Clone, U> UlpsEq for Construct<T, U> where T: UlpsEq<Epsilon=T>, U: UlpsEq<Epsilon=T> {
+impl<T: Clone, U, S: Gamma> UlpsEq for Construct<T, U, S> where T: UlpsEq<Epsilon=T>, U: UlpsEq<Epsilon=T> {
     fn default_max_ulps() -> u32 {
         T::default_max_ulps()
     }
+    #[allow(clippy::clone_on_copy)]
     fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
         ulps_eq!(self.re_ref(), other.re_ref(), epsilon=epsilon.clone(), max_ulps=max_ulps.clone()) &&
         ulps_eq!(self.im_ref(), other.im_ref(), epsilon=epsilon, max_ulps=max_ulps)
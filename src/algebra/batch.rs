@@ -0,0 +1,366 @@
+//! Structure-of-arrays containers for large batches of hypercomplex numbers.
+//!
+//! Each scalar component is stored in a separate contiguous array.
+//! Batched operations are single loops over the elements that compute all components of an element at once,
+//! so the compiler can auto-vectorize them across the elements.
+//! Products are computed using the basis multiplication table,
+//! so results may differ from the ones of `Construct` operations by rounding errors.
+
+use alloc::{vec, vec::Vec};
+use core::{
+    array,
+    ops::{Neg, Add, Sub, Mul, Div},
+};
+use num_traits::{Float, Zero};
+use super::{construct::Construct, gamma::{Gamma, Elliptic}, flat::{self, Flat}, specific::basis_mul};
+
+
+/// Hypercomplex number of the standard Cayley–Dickson construction, i.e. with [`Elliptic`] gamma at each level.
+///
+/// Batched products use the multiplication table of the standard construction,
+/// so only such numbers can be stored in [`ConstructSoA`], dual and split ones are rejected:
+///
+/// ```compile_fail
+/// use hcomplex::{Dual, batch::ConstructSoA};
+/// let mut soa = ConstructSoA::<f64, 2>::new();
+/// soa.push(Dual::new(1.0, 2.0));
+/// ```
+pub trait StandardFlat<T>: Flat<T> {}
+
+impl<T> StandardFlat<T> for Construct<T, T, Elliptic> {}
+impl<T, U, V: Gamma> StandardFlat<T> for Construct<T, Construct<T, U, V>, Elliptic> where Construct<T, U, V>: StandardFlat<T> {}
+
+/// Batch of hypercomplex numbers of dimension `N` stored as structure of arrays.
+///
+/// All component arrays have the same length.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructSoA<T, const N: usize> {
+    data: [Vec<T>; N],
+}
+
+/// Batch of complex numbers.
+pub type ComplexSoA<T> = ConstructSoA<T, 2>;
+/// Batch of quaternions.
+pub type QuaternionSoA<T> = ConstructSoA<T, 4>;
+/// Batch of octonions.
+pub type OctonionSoA<T> = ConstructSoA<T, 8>;
+/// Batch of sedenions.
+pub type SedenionSoA<T> = ConstructSoA<T, 16>;
+
+
+/// Level of the algebra of dimension `dim`.
+fn level(dim: usize) -> usize {
+    assert!(dim.is_power_of_two() && dim >= 2, "dimension must be a power of two");
+    dim.trailing_zeros() as usize
+}
+
+impl<T, const N: usize> ConstructSoA<T, N> {
+    /// Create empty batch.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    /// Create empty batch with capacity for `n` elements.
+    pub fn with_capacity(n: usize) -> Self {
+        level(N);
+        Self { data: array::from_fn(|_| Vec::with_capacity(n)) }
+    }
+    /// Create from component arrays.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    pub fn from_components(data: [Vec<T>; N]) -> Self {
+        level(N);
+        assert!(data.iter().all(|c| c.len() == data[0].len()), "component arrays have different lengths");
+        Self { data }
+    }
+    /// Split into component arrays.
+    pub fn into_components(self) -> [Vec<T>; N] {
+        self.data
+    }
+
+    /// Number of elements in the batch.
+    pub fn len(&self) -> usize {
+        self.data[0].len()
+    }
+    /// Whether the batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Array of `n`-th scalar components of all elements.
+    pub fn component(&self, n: usize) -> &[T] {
+        &self.data[n]
+    }
+    /// Mutable array of `n`-th scalar components of all elements.
+    pub fn component_mut(&mut self, n: usize) -> &mut [T] {
+        &mut self.data[n]
+    }
+
+    /// Append element to the end of the batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to the dimension of the element.
+    pub fn push<C: StandardFlat<T>>(&mut self, x: C) {
+        let array: [T; N] = flat::into_array(x);
+        for (c, v) in self.data.iter_mut().zip(array) {
+            c.push(v);
+        }
+    }
+    /// Get `n`-th element of the batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to the dimension of the element.
+    pub fn get<C: StandardFlat<T>>(&self, n: usize) -> Option<C> where T: Clone {
+        if n < self.len() {
+            let array: [T; N] = array::from_fn(|k| self.data[k][n].clone());
+            Some(flat::from_array(array))
+        } else {
+            None
+        }
+    }
+    /// Convert into vector of elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to the dimension of the element.
+    pub fn to_vec<C: StandardFlat<T>>(&self) -> Vec<C> where T: Clone {
+        (0..self.len()).map(|n| self.get(n).unwrap()).collect()
+    }
+}
+
+impl<T, const N: usize> Default for ConstructSoA<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Panics
+///
+/// Panics if `N` is not equal to the dimension of the element.
+impl<T, C: StandardFlat<T>, const N: usize> From<Vec<C>> for ConstructSoA<T, N> {
+    fn from(v: Vec<C>) -> Self {
+        let mut soa = Self::with_capacity(v.len());
+        for x in v {
+            soa.push(x);
+        }
+        soa
+    }
+}
+/// # Panics
+///
+/// Panics if `N` is not equal to the dimension of the element.
+impl<T: Clone, C: StandardFlat<T>, const N: usize> From<ConstructSoA<T, N>> for Vec<C> {
+    fn from(soa: ConstructSoA<T, N>) -> Self {
+        soa.to_vec()
+    }
+}
+
+/// Multiplication table of the basis elements of dimension `N`, see [`basis_mul`].
+const fn mul_table<const N: usize>() -> [[(i8, usize); N]; N] {
+    let lvl = N.trailing_zeros() as usize;
+    let mut table = [[(0, 0); N]; N];
+    let mut i = 0;
+    while i < N {
+        let mut j = 0;
+        while j < N {
+            table[i][j] = basis_mul(lvl, i, j);
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Terms `(coefficient, component)` of each output component of the product of `z` by a constant.
+type MulTerms<T, const N: usize> = [[(T, usize); N]; N];
+
+impl<T, const N: usize> ConstructSoA<T, N> where T: Copy + Zero + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+    const TABLE: [[(i8, usize); N]; N] = mul_table::<N>();
+
+    /// Batch of zeros.
+    fn filled(len: usize) -> Self {
+        Self { data: array::from_fn(|_| vec![T::zero(); len]) }
+    }
+    /// Compute each element of the result from the elements of `self` and `other` with the same index.
+    ///
+    /// This is a single loop over the elements, all components are kept in registers.
+    fn zip_lanes<F: FnMut([T; N], [T; N]) -> [T; N]>(&self, other: &Self, mut f: F) -> Self {
+        let len = self.len();
+        assert_eq!(len, other.len());
+        let (a, b) = (self.slices(), other.slices());
+        let mut out = Self::filled(len);
+        for n in 0..len {
+            let r = f(array::from_fn(|k| a[k][n]), array::from_fn(|k| b[k][n]));
+            for (dst, x) in out.data.iter_mut().zip(r) {
+                dst[n] = x;
+            }
+        }
+        out
+    }
+    /// Compute each element of the result from the element of `self` with the same index.
+    fn map_lanes<F: FnMut([T; N]) -> [T; N]>(&self, mut f: F) -> Self {
+        self.zip_lanes(self, |x, _| f(x))
+    }
+    /// Component arrays truncated to the length of the batch.
+    fn slices(&self) -> [&[T]; N] {
+        let len = self.len();
+        array::from_fn(|k| &self.data[k][..len])
+    }
+
+    /// Product of single elements.
+    fn mul_lane(a: [T; N], b: [T; N]) -> [T; N] {
+        let mut r = [T::zero(); N];
+        for (i, row) in Self::TABLE.iter().enumerate() {
+            for (j, &(sign, k)) in row.iter().enumerate() {
+                r[k] = if sign > 0 { r[k] + a[i] * b[j] } else { r[k] - a[i] * b[j] };
+            }
+        }
+        r
+    }
+    /// Conjugate of single element.
+    fn conj_lane(x: [T; N]) -> [T; N] {
+        array::from_fn(|k| if k == 0 { x[k] } else { -x[k] })
+    }
+    /// Squared norm of single element.
+    fn norm_sqr_lane(x: &[T; N]) -> T {
+        x.iter().fold(T::zero(), |s, &c| s + c * c)
+    }
+    /// Inverse of single element.
+    fn inv_lane(x: [T; N]) -> [T; N] where T: Div<Output=T> {
+        let n = Self::norm_sqr_lane(&x);
+        Self::conj_lane(x).map(|c| c / n)
+    }
+    /// Product of single element by constant described by `terms`.
+    fn mul_terms_lane(terms: &MulTerms<T, N>, dim: usize, z: &[T; N]) -> [T; N] {
+        array::from_fn(|k| terms[k][..dim].iter().fold(T::zero(), |s, &(c, j)| s + c * z[j]))
+    }
+    /// Add constant `c` of lower dimension to single element.
+    fn add_lane(mut x: [T; N], c: &[T]) -> [T; N] {
+        for (x, &c) in x.iter_mut().zip(c) {
+            *x = *x + c;
+        }
+        x
+    }
+
+    /// Terms of the product of `z` by `c` from the left or from the right.
+    fn mul_terms(c: &[T], left: bool) -> MulTerms<T, N> {
+        let dim = c.len();
+        assert!(dim <= N, "dimension of the multiplier exceeds the dimension of the batch");
+        let lvl = level(dim);
+        let mut terms = [[(T::zero(), 0); N]; N];
+        let mut count = [0; N];
+        for (b, base) in (0..N).step_by(dim).enumerate() {
+            // `c` is embedded as `(c, 0)`, so `c (x, y) = (c x, y c)` and `(x, y) c = (x c, y c̄)`.
+            // Only the first part is multiplied from the left, the others from the right by `c` or `c̄`.
+            let ones = b.count_ones();
+            let (block_left, conj) = match (left, b) {
+                (true, 0) => (true, false),
+                (true, _) => (false, ones % 2 == 0),
+                (false, _) => (false, ones % 2 == 1),
+            };
+            for i in 0..dim {
+                for j in 0..dim {
+                    let (sign, k) = basis_mul(lvl, i, j);
+                    let (ck, zj) = if block_left { (i, j) } else { (j, i) };
+                    let ci = if conj && ck != 0 { -c[ck] } else { c[ck] };
+                    let k = base + k;
+                    terms[k][count[k]] = (if sign > 0 { ci } else { -ci }, base + zj);
+                    count[k] += 1;
+                }
+            }
+        }
+        terms
+    }
+
+    /// Multiply every element `z` by `c` from the left, i.e. compute `c * z`.
+    ///
+    /// Dimension of `c` may be lower than `N`, then `c` is multiplied by each part of `z` of its dimension,
+    /// the same way as `Construct` multiplication by an element of lower level does.
+    pub fn premul<C: StandardFlat<T>>(&self, c: &C) -> Self {
+        let c = flat::as_slice(c);
+        let terms = Self::mul_terms(c, true);
+        self.map_lanes(|z| Self::mul_terms_lane(&terms, c.len(), &z))
+    }
+    /// Multiply every element `z` by `c` from the right, i.e. compute `z * c`.
+    ///
+    /// Dimension of `c` may be lower than `N`, see [`premul`](Self::premul).
+    pub fn postmul<C: StandardFlat<T>>(&self, c: &C) -> Self {
+        let c = flat::as_slice(c);
+        let terms = Self::mul_terms(c, false);
+        self.map_lanes(|z| Self::mul_terms_lane(&terms, c.len(), &z))
+    }
+    /// Add `c` to every element, dimension of `c` may be lower than `N`.
+    pub fn add_const<C: StandardFlat<T>>(&self, c: &C) -> Self {
+        let c = flat::as_slice(c);
+        assert!(c.len() <= N, "dimension of the term exceeds the dimension of the batch");
+        self.map_lanes(|x| Self::add_lane(x, c))
+    }
+    /// Apply `z -> (a z + b) (c z + d)⁻¹` to every element, coefficients may be of lower dimension than `N`.
+    pub(crate) fn moebius(&self, a: &[T], b: &[T], c: &[T], d: &[T]) -> Self where T: Div<Output=T> {
+        assert!(b.len() <= N && d.len() <= N, "dimension of the term exceeds the dimension of the batch");
+        let (ta, tc) = (Self::mul_terms(a, true), Self::mul_terms(c, true));
+        self.map_lanes(|z| {
+            let num = Self::add_lane(Self::mul_terms_lane(&ta, a.len(), &z), b);
+            let den = Self::add_lane(Self::mul_terms_lane(&tc, c.len(), &z), d);
+            Self::mul_lane(num, Self::inv_lane(den))
+        })
+    }
+
+    /// Elementwise sum.
+    pub fn add(&self, other: &Self) -> Self {
+        self.zip_lanes(other, |a, b| array::from_fn(|k| a[k] + b[k]))
+    }
+    /// Elementwise difference.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.zip_lanes(other, |a, b| array::from_fn(|k| a[k] - b[k]))
+    }
+    /// Elementwise product `self[n] * other[n]`.
+    pub fn mul(&self, other: &Self) -> Self {
+        self.zip_lanes(other, Self::mul_lane)
+    }
+    /// Conjugate every element.
+    pub fn conj(&self) -> Self {
+        self.map_lanes(Self::conj_lane)
+    }
+    /// Squared norm of every element.
+    pub fn norm_sqr(&self) -> Vec<T> {
+        let src = self.slices();
+        (0..self.len()).map(|n| Self::norm_sqr_lane(&array::from_fn(|k| src[k][n]))).collect()
+    }
+    /// Divide every element by the corresponding scalar.
+    pub fn div_scalar(&self, s: &[T]) -> Self where T: Div<Output=T> {
+        assert_eq!(self.len(), s.len());
+        let mut n = 0;
+        self.map_lanes(|x| {
+            let r = x.map(|c| c / s[n]);
+            n += 1;
+            r
+        })
+    }
+    /// Inverse of every element.
+    pub fn inv(&self) -> Self where T: Div<Output=T> {
+        self.map_lanes(Self::inv_lane)
+    }
+    /// Elementwise right division `self[n] * other[n]⁻¹`.
+    pub fn div(&self, other: &Self) -> Self where T: Div<Output=T> {
+        self.zip_lanes(other, |a, b| Self::mul_lane(a, Self::inv_lane(b)))
+    }
+}
+
+impl<T: Float, const N: usize> ConstructSoA<T, N> {
+    /// Norm of every element.
+    pub fn norm(&self) -> Vec<T> {
+        let mut out = self.norm_sqr();
+        out.iter_mut().for_each(|x| *x = x.sqrt());
+        out
+    }
+    /// Normalize every element.
+    pub fn normalize(&self) -> Self {
+        self.map_lanes(|x| {
+            let n = Self::norm_sqr_lane(&x).sqrt();
+            x.map(|c| c / n)
+        })
+    }
+}
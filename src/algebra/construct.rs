@@ -1,27 +1,36 @@
 use core::{
+    str::FromStr,
     ops::{
-        Neg, Add, Sub, Mul, Div, Rem,
+        Neg, Add, Sub, Mul, Div,
         AddAssign, SubAssign, MulAssign, DivAssign,
     },
     marker::PhantomData,
 };
 use num_traits::{Zero, One, Float, Inv, Num};
-use super::traits::{Conj, Dot, NormSqr, Norm, NormL1, Algebra};
+use super::{
+    traits::{Conj, Dot, NormSqr, Norm, NormL1, Algebra, Commutative, Associative, Alternative, DivisionAlgebra},
+    parse::{self, ParseError},
+    format::Format,
+    euclid::RoundDiv,
+    gamma::{Gamma, Elliptic},
+};
 
 
 /// Cayley–Dickson construction, a basic building block.
 ///
-/// Structure takes two type parameters:
+/// Structure takes three type parameters:
 /// + The first one, `T`: a scalar type the algebra is built over.
 /// + The second one, `U`: is a type of two components of the construction: `re` and `im`.
+/// + The third one, `S`: the [`Gamma`] parameter of the construction, which is [`Elliptic`] by default.
+#[repr(C)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct Construct<T, U> {
+pub struct Construct<T, U, S = Elliptic> {
     re: U,
     im: U,
-    ph: PhantomData<T>,
+    ph: PhantomData<(T, S)>,
 }
 
-impl<T, U> Construct<T, U> {
+impl<T, U, S: Gamma> Construct<T, U, S> {
     /// Create from real and imaginary parts.
     pub fn new(re: U, im: U) -> Self {
         Self { re, im, ph: PhantomData }
@@ -44,7 +53,7 @@ impl<T, U> Construct<T, U> {
         &mut self.im
     }
 }
-impl<T, U> Construct<T, U> where U: Clone {
+impl<T, U, S: Gamma> Construct<T, U, S> where U: Clone {
     pub fn re(&self) -> U {
         self.re.clone()
     }
@@ -53,98 +62,162 @@ impl<T, U> Construct<T, U> where U: Clone {
     }
 }
 
-impl<T, U> Conj for Construct<T, U> where U: Conj + Neg<Output=U> {
+impl<T, U, S: Gamma> Conj for Construct<T, U, S> where U: Conj + Neg<Output=U> {
     fn conj(self) -> Self {
         Self::new(self.re.conj(), -self.im)
     }
 }
 
-impl<T, U> NormSqr for Construct<T, U> where T: Add<Output=T>, U: NormSqr<Output=T> {
+impl<T, U, S: Gamma> NormSqr for Construct<T, U, S> where T: Add<Output=T> + Sub<Output=T>, U: NormSqr<Output=T> {
+    type Output = T;
+    fn norm_sqr(self) -> T {
+        S::sub_gamma(self.re.norm_sqr(), self.im.norm_sqr())
+    }
+}
+impl<'a, T, U, S: Gamma> NormSqr for &'a Construct<T, U, S> where T: Add<Output=T> + Sub<Output=T>, &'a U: NormSqr<Output=T> {
     type Output = T;
     fn norm_sqr(self) -> T {
-        self.re.norm_sqr() + self.im.norm_sqr()
+        S::sub_gamma(self.re.norm_sqr(), self.im.norm_sqr())
     }
 }
+/// Euclidean norm is defined only for the standard Cayley–Dickson construction.
 impl<T, U> Norm for Construct<T, U> where T: Float, Self: NormSqr<Output=T> {
     type Output = T;
     fn norm(self) -> T {
         self.norm_sqr().sqrt()
     }
 }
-impl<T, U> NormL1 for Construct<T, U> where T: Add<Output=T>, U: NormL1<Output=T> {
+impl<T, U, S: Gamma> NormL1 for Construct<T, U, S> where T: Add<Output=T>, U: NormL1<Output=T> {
     type Output = T;
     fn norm_l1(self) -> T {
         self.re.norm_l1() + self.im.norm_l1()
     }
 }
 
-impl<T, U> Neg for Construct<T, U> where U: Neg<Output=U> {
+impl<T, U, S: Gamma> Neg for Construct<T, U, S> where U: Neg<Output=U> {
     type Output = Self;
     fn neg(self) -> Self {
         Self::new(-self.re, -self.im)
     }
 }
 
-impl<T, U> Add for Construct<T, U> where U: Add<Output=U> {
+impl<T, U, S: Gamma> Add for Construct<T, U, S> where U: Add<Output=U> {
     type Output = Self;
     fn add(self, other: Self) -> Self::Output {
         Self::new(self.re + other.re, self.im + other.im)
     }
 }
-impl<T, U> Sub for Construct<T, U> where U: Sub<Output=U> {
+impl<T, U, S: Gamma> Sub for Construct<T, U, S> where U: Sub<Output=U> {
     type Output = Self;
     fn sub(self, other: Self) -> Self::Output {
         Self::new(self.re - other.re, self.im - other.im)
     }
 }
-impl<T, U> Add<T> for Construct<T, U> where U: Add<T, Output=U> {
+impl<T, U, S: Gamma> Add<T> for Construct<T, U, S> where U: Add<T, Output=U> {
     type Output = Self;
     fn add(self, other: T) -> Self::Output {
         Self::new(self.re + other, self.im)
     }
 }
-impl<T, U> Sub<T> for Construct<T, U> where U: Sub<T, Output=U> {
+impl<T, U, S: Gamma> Sub<T> for Construct<T, U, S> where U: Sub<T, Output=U> {
     type Output = Self;
     fn sub(self, other: T) -> Self::Output {
         Self::new(self.re - other, self.im)
     }
 }
 
-impl<T, U> Mul<T> for Construct<T, U> where T: Clone, U: Mul<T, Output=U> {
+impl<T, U, S: Gamma> Mul<T> for Construct<T, U, S> where T: Clone, U: Mul<T, Output=U> {
     type Output = Self;
     fn mul(self, other: T) -> Self::Output {
         Self::new(self.re * other.clone(), self.im * other)
     }
 }
-impl<T, U> Div<T> for Construct<T, U> where T: Clone, U: Div<T, Output=U> {
+impl<T, U, S: Gamma> Div<T> for Construct<T, U, S> where T: Clone, U: Div<T, Output=U> {
     type Output = Self;
     fn div(self, other: T) -> Self::Output {
         Self::new(self.re / other.clone(), self.im / other)
     }
 }
-impl<T, U> Mul for Construct<T, U> where U: Clone + Conj + Mul<Output=U> + Add<Output=U> + Sub<Output=U> {
+impl<T, U, S: Gamma> Mul for Construct<T, U, S> where U: Clone + Conj + Mul<Output=U> + Add<Output=U> + Sub<Output=U> {
     type Output = Self;
     fn mul(self, other: Self) -> Self::Output {
+        let ((a, b), (c, d)) = (self.split(), other.split());
         Self::new(
-            self.re() * other.re() - other.im().conj() * self.im(),
-            other.im() * self.re() + self.im() * other.re().conj(),
+            S::add_gamma(a.clone() * c.clone(), d.clone().conj() * b.clone()),
+            d * a + b * c.conj(),
         )
     }
 }
-impl<T, U> Inv for Construct<T, U> where Self: Clone + Conj + NormSqr<Output=T> + Div<T, Output=Self> {
+impl<T, U, S: Gamma> Inv for Construct<T, U, S> where Self: Clone + Conj + NormSqr<Output=T> + Div<T, Output=Self> {
     type Output = Self;
     fn inv(self) -> Self {
         self.clone().conj() / self.norm_sqr()
     }
 }
-impl<T, U> Div for Construct<T, U> where Self: Inv<Output=Self> + Mul<Output=Self> {
+impl<T, U, S: Gamma> Div for Construct<T, U, S> where Self: Inv<Output=Self> + Mul<Output=Self> {
     type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Self) -> Self::Output {
         self * other.inv()
     }
 }
 
-impl<T, U> Zero for Construct<T, U> where U: Zero {
+impl<'a, T, U, S: Gamma> Neg for &'a Construct<T, U, S> where &'a U: Neg<Output=U> {
+    type Output = Construct<T, U, S>;
+    fn neg(self) -> Self::Output {
+        Construct::new(-&self.re, -&self.im)
+    }
+}
+impl<'a, 'b, T, U, S: Gamma> Add<&'b Construct<T, U, S>> for &'a Construct<T, U, S> where &'a U: Add<&'b U, Output=U> {
+    type Output = Construct<T, U, S>;
+    fn add(self, other: &'b Construct<T, U, S>) -> Self::Output {
+        Construct::new(&self.re + &other.re, &self.im + &other.im)
+    }
+}
+impl<'a, 'b, T, U, S: Gamma> Sub<&'b Construct<T, U, S>> for &'a Construct<T, U, S> where &'a U: Sub<&'b U, Output=U> {
+    type Output = Construct<T, U, S>;
+    fn sub(self, other: &'b Construct<T, U, S>) -> Self::Output {
+        Construct::new(&self.re - &other.re, &self.im - &other.im)
+    }
+}
+/// Only the conjugated halves of `other` are cloned.
+impl<'b, T, U, S: Gamma> Mul<&'b Construct<T, U, S>> for &Construct<T, U, S> where
+    U: Clone + Conj + Add<Output=U> + Sub<Output=U>,
+    for<'x, 'y> &'x U: Mul<&'y U, Output=U>,
+{
+    type Output = Construct<T, U, S>;
+    fn mul(self, other: &'b Construct<T, U, S>) -> Self::Output {
+        let (cc, dc) = (other.re.clone().conj(), other.im.clone().conj());
+        Construct::new(
+            S::add_gamma(&self.re * &other.re, &dc * &self.im),
+            &other.im * &self.re + &self.im * &cc,
+        )
+    }
+}
+/// Only the real half is cloned to be conjugated.
+impl<'a, T, U, S: Gamma> Inv for &'a Construct<T, U, S> where
+    U: Clone + Conj,
+    &'a U: Neg<Output=U>,
+    Self: NormSqr<Output=T>,
+    Construct<T, U, S>: Div<T, Output=Construct<T, U, S>>,
+{
+    type Output = Construct<T, U, S>;
+    fn inv(self) -> Self::Output {
+        Construct::new(self.re.clone().conj(), -&self.im) / self.norm_sqr()
+    }
+}
+impl<'a, 'b, T, U, S: Gamma> Div<&'b Construct<T, U, S>> for &'a Construct<T, U, S> where
+    &'b Construct<T, U, S>: Inv<Output=Construct<T, U, S>>,
+    for<'x> &'a Construct<T, U, S>: Mul<&'x Construct<T, U, S>, Output=Construct<T, U, S>>,
+{
+    type Output = Construct<T, U, S>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: &'b Construct<T, U, S>) -> Self::Output {
+        self * &other.inv()
+    }
+}
+
+impl<T, U, S: Gamma> Zero for Construct<T, U, S> where U: Zero {
     fn zero() -> Self {
         Self::new(U::zero(), U::zero())
     }
@@ -152,7 +225,7 @@ impl<T, U> Zero for Construct<T, U> where U: Zero {
         self.re.is_zero() && self.im.is_zero()
     }
 }
-impl<T, U> One for Construct<T, U> where U: Zero + One, Self: Mul<Output=Self> {
+impl<T, U, S: Gamma> One for Construct<T, U, S> where U: Zero + One, Self: Mul<Output=Self> {
     fn one() -> Self {
         Self::new(U::one(), U::zero())
     }
@@ -164,7 +237,7 @@ impl<T, U> Construct<T, U> where Self: Clone + Norm<Output=T> + Div<T, Output=Se
     }
 }
 
-impl<T, U> Dot for Construct<T, U> where T: Add<Output=T>, U: Dot<Output=T> {
+impl<T, U, S: Gamma> Dot for Construct<T, U, S> where T: Add<Output=T>, U: Dot<Output=T> {
     type Output = T;
     fn dot(self, other: Self) -> T {
         let (l, r) = (self.split(), other.split());
@@ -172,108 +245,152 @@ impl<T, U> Dot for Construct<T, U> where T: Add<Output=T>, U: Dot<Output=T> {
     }
 }
 
-/// Not implemented yet.
-impl<T: Num + Algebra + Clone, U: Num + Algebra<T> + Clone> Rem for Construct<T, U> {
-    type Output = Self;
-    fn rem(self, _other: Self) -> Self::Output {
-        unimplemented!()
+/// Parses the notations described in the [`parse`](crate::parse) module.
+impl<T: Num + Algebra + Clone, U: Num + Algebra<T> + RoundDiv<T> + Clone> Num for Construct<T, U> where Self: Format<T> {
+    type FromStrRadixErr = ParseError;
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        parse::parse(src, radix)
     }
 }
-/// Not implemented yet.
-impl<T: Num + Algebra + Clone, U: Num + Algebra<T> + Clone> Num for Construct<T, U> {
-    type FromStrRadixErr = ();
-    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        unimplemented!()
+/// Parses the notations described in the [`parse`](crate::parse) module.
+impl<T: Num + Neg<Output=T>, U: Zero, S: Gamma> FromStr for Construct<T, U, S> where Self: Format<T> {
+    type Err = ParseError;
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        parse::parse(src, 10)
     }
 }
 
-impl<T, U> Algebra<T> for Construct<T, U> where T: Algebra + Clone, U: Algebra<T> + Clone {}
+impl<T, U, S: Gamma> Algebra<T> for Construct<T, U, S> where T: Algebra + Clone, U: Algebra<T> + Clone {}
+
+// Each step of the Cayley–Dickson construction loses one of the algebraic properties,
+// so complex numbers are commutative, quaternions are associative and octonions are alternative.
+// Only the elliptic algebras are division ones.
+impl<T: Algebra, S: Gamma> Commutative for Construct<T, T, S> {}
+
+impl<T: Algebra, S: Gamma> Associative for Construct<T, T, S> {}
+impl<T: Algebra, S: Gamma, V: Gamma> Associative for Construct<T, Construct<T, T, V>, S> {}
+
+impl<T: Algebra, S: Gamma> Alternative for Construct<T, T, S> {}
+impl<T: Algebra, S: Gamma, V: Gamma> Alternative for Construct<T, Construct<T, T, V>, S> {}
+impl<T: Algebra, S: Gamma, V: Gamma, W: Gamma> Alternative for Construct<T, Construct<T, Construct<T, T, W>, V>, S> {}
 
-macro_rules! radd { ($T:ident) => (
+impl<T: Float + Algebra> DivisionAlgebra for Construct<T, T, Elliptic> {}
+impl<T: Float + Algebra> DivisionAlgebra for Construct<T, Construct<T, T, Elliptic>, Elliptic> {}
+impl<T: Float + Algebra> DivisionAlgebra for Construct<T, Construct<T, Construct<T, T, Elliptic>, Elliptic>, Elliptic> {}
+
+/// Implement reverse operations `x + a`, `x - a`, `x * a` and `x / a` for scalar types `x: T` and hypercomplex numbers `a: Construct<T, ..>`.
+///
+/// Such impls can't be generic over `T` due to the orphan rules, so they are provided for built-in scalars only.
+/// Custom scalar types can opt in by invoking this macro in the crate where they are defined:
+///
+/// ```ignore
+/// hcomplex::impl_reverse_ops!(MyScalar);
+/// ```
+#[macro_export]
+macro_rules! impl_reverse_ops { ($($T:ty),* $(,)?) => ($(
     /// Workaround for reverse addition.
-    impl<U> Add<Construct<$T, U>> for $T where Construct<$T, U>: Add<$T, Output=Construct<$T, U>> {
-        type Output = Construct<$T, U>;
-        fn add(self, other: Construct<$T, U>) -> Self::Output {
+    impl<U, S: $crate::Gamma> ::core::ops::Add<$crate::Construct<$T, U, S>> for $T where
+        $crate::Construct<$T, U, S>: ::core::ops::Add<$T, Output=$crate::Construct<$T, U, S>>,
+    {
+        type Output = $crate::Construct<$T, U, S>;
+        fn add(self, other: $crate::Construct<$T, U, S>) -> Self::Output {
             other + self
         }
     }
-) }
-macro_rules! rsub { ($T:ident) => (
     /// Workaround for reverse subtraction.
-    impl<U> Sub<Construct<$T, U>> for $T where Construct<$T, U>: Neg<Output=Construct<$T, U>> + Add<$T, Output=Construct<$T, U>> {
-        type Output = Construct<$T, U>;
-        fn sub(self, other: Construct<$T, U>) -> Self::Output {
+    impl<U, S: $crate::Gamma> ::core::ops::Sub<$crate::Construct<$T, U, S>> for $T where
+        $crate::Construct<$T, U, S>: ::core::ops::Neg<Output=$crate::Construct<$T, U, S>> + ::core::ops::Add<$T, Output=$crate::Construct<$T, U, S>>,
+    {
+        type Output = $crate::Construct<$T, U, S>;
+        fn sub(self, other: $crate::Construct<$T, U, S>) -> Self::Output {
             -other + self
         }
     }
-) }
-macro_rules! rmul { ($T:ident) => (
     /// Workaround for reverse multiplication.
-    impl<U> Mul<Construct<$T, U>> for $T where Construct<$T, U>: Mul<$T, Output=Construct<$T, U>> {
-        type Output = Construct<$T, U>;
-        fn mul(self, other: Construct<$T, U>) -> Self::Output {
+    impl<U, S: $crate::Gamma> ::core::ops::Mul<$crate::Construct<$T, U, S>> for $T where
+        $crate::Construct<$T, U, S>: ::core::ops::Mul<$T, Output=$crate::Construct<$T, U, S>>,
+    {
+        type Output = $crate::Construct<$T, U, S>;
+        fn mul(self, other: $crate::Construct<$T, U, S>) -> Self::Output {
             other*self
         }
     }
-) }
-macro_rules! rdiv { ($T:ident) => (
     /// Workaround for reverse division.
-    impl<U> Div<Construct<$T, U>> for $T where Construct<$T, U>: Inv<Output=Construct<$T, U>> + Mul<$T, Output=Construct<$T, U>> + Clone {
-        type Output = Construct<$T, U>;
-        fn div(self, other: Construct<$T, U>) -> Self::Output {
-            other.inv()*self
+    impl<U, S: $crate::Gamma> ::core::ops::Div<$crate::Construct<$T, U, S>> for $T where
+        $crate::Construct<$T, U, S>: $crate::prelude::Inv<Output=$crate::Construct<$T, U, S>> + ::core::ops::Mul<$T, Output=$crate::Construct<$T, U, S>>,
+    {
+        type Output = $crate::Construct<$T, U, S>;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, other: $crate::Construct<$T, U, S>) -> Self::Output {
+            $crate::prelude::Inv::inv(other)*self
         }
     }
-) }
-macro_rules! reverse { ($T:ident) => (
-    radd!($T);
-    rsub!($T);
-    rmul!($T);
-    rdiv!($T);
-) }
-reverse!(f32);
-reverse!(f64);
+)*) }
+
+impl_reverse_ops!(i8, i16, i32, i64, f32, f64);
 
 
-impl<T, U> AddAssign for Construct<T, U> where U: AddAssign {
-    fn add_assign(&mut self, other: Self) -> () {
+impl<T, U, S: Gamma> AddAssign for Construct<T, U, S> where U: AddAssign {
+    fn add_assign(&mut self, other: Self) {
         self.re += other.re;
         self.im += other.im;
     }
 }
-impl<T, U> SubAssign for Construct<T, U> where U: SubAssign {
-    fn sub_assign(&mut self, other: Self) -> () {
+impl<T, U, S: Gamma> SubAssign for Construct<T, U, S> where U: SubAssign {
+    fn sub_assign(&mut self, other: Self) {
         self.re -= other.re;
         self.im -= other.im;
     }
 }
-impl<T, U> AddAssign<T> for Construct<T, U> where U: AddAssign<T> {
-    fn add_assign(&mut self, other: T) -> () {
+impl<T, U, S: Gamma> AddAssign<T> for Construct<T, U, S> where U: AddAssign<T> {
+    fn add_assign(&mut self, other: T) {
         self.re += other;
     }
 }
-impl<T, U> SubAssign<T> for Construct<T, U> where U: SubAssign<T> {
-    fn sub_assign(&mut self, other: T) -> () {
+impl<T, U, S: Gamma> SubAssign<T> for Construct<T, U, S> where U: SubAssign<T> {
+    fn sub_assign(&mut self, other: T) {
         self.re -= other;
     }
 }
-impl<T, U> MulAssign<T> for Construct<T, U> where Self: Clone + Mul<T, Output=Self> {
-    fn mul_assign(&mut self, other: T) -> () {
+impl<T, U, S: Gamma> MulAssign<T> for Construct<T, U, S> where Self: Clone + Mul<T, Output=Self> {
+    fn mul_assign(&mut self, other: T) {
         *self = self.clone() * other;
     }
 }
-impl<T, U> DivAssign<T> for Construct<T, U> where Self: Clone + Div<T, Output=Self> {
-    fn div_assign(&mut self, other: T) -> () {
+impl<T, U, S: Gamma> DivAssign<T> for Construct<T, U, S> where Self: Clone + Div<T, Output=Self> {
+    fn div_assign(&mut self, other: T) {
         *self = self.clone() / other;
     }
 }
-impl<T, U> MulAssign for Construct<T, U> where Self: Clone + Mul<Output=Self> {
-    fn mul_assign(&mut self, other: Self) -> () {
+impl<T, U, S: Gamma> MulAssign for Construct<T, U, S> where Self: Clone + Mul<Output=Self> {
+    fn mul_assign(&mut self, other: Self) {
         *self = self.clone() * other;
     }
 }
-impl<T, U> DivAssign for Construct<T, U> where Self: Clone + Div<Output=Self> {
-    fn div_assign(&mut self, other: Self) -> () {
+impl<T, U, S: Gamma> DivAssign for Construct<T, U, S> where Self: Clone + Div<Output=Self> {
+    fn div_assign(&mut self, other: Self) {
         *self = self.clone() / other;
     }
 }
+impl<'a, T, U, S: Gamma> AddAssign<&'a Self> for Construct<T, U, S> where U: AddAssign<&'a U> {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.re += &other.re;
+        self.im += &other.im;
+    }
+}
+impl<'a, T, U, S: Gamma> SubAssign<&'a Self> for Construct<T, U, S> where U: SubAssign<&'a U> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.re -= &other.re;
+        self.im -= &other.im;
+    }
+}
+impl<'a, T, U, S: Gamma> MulAssign<&'a Self> for Construct<T, U, S> where for<'x> &'x Self: Mul<&'a Self, Output=Self> {
+    fn mul_assign(&mut self, other: &'a Self) {
+        *self = &*self * other;
+    }
+}
+impl<'a, T, U, S: Gamma> DivAssign<&'a Self> for Construct<T, U, S> where for<'x> &'x Self: Div<&'a Self, Output=Self> {
+    fn div_assign(&mut self, other: &'a Self) {
+        *self = &*self / other;
+    }
+}
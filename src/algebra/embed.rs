@@ -0,0 +1,180 @@
+//! Embedding of lower level algebras into higher level ones and mixed arithmetic between them.
+//!
+//! Lower level number `x` is embedded as `(x, 0)` at each step of the construction, i.e. it occupies the first components.
+//! Mixed operations give the same result as the operations on the embedded value.
+
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    ops::{
+        Neg, Add, Sub, Mul, Div,
+        AddAssign, SubAssign, MulAssign, DivAssign,
+    },
+};
+use num_traits::{Zero, Inv};
+use super::{*, flat::{self, Flat}};
+
+
+/// Error returned when the projection onto a lower level algebra discards non-zero components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProjectError {
+    index: usize,
+    count: usize,
+}
+
+impl ProjectError {
+    /// Index of the first discarded non-zero component.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// Number of discarded non-zero components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl Display for ProjectError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} non-zero components are discarded, starting from {}", self.count, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProjectError {}
+
+
+/// Take the first components of `x`, which must be followed by zeros only.
+fn project<T: Zero + Clone, U, W: Gamma, H: Flat<T>>(x: &H) -> Result<Construct<T, U, W>, ProjectError> where Construct<T, U, W>: Flat<T> {
+    let dim = Construct::<T, U, W>::DIM;
+    let (low, high) = flat::as_slice(x).split_at(dim);
+    let mut nonzero = high.iter().enumerate().filter(|(_, c)| !c.is_zero()).map(|(i, _)| i + dim);
+    match nonzero.next() {
+        Some(index) => Err(ProjectError { index, count: nonzero.count() + 1 }),
+        None => Ok(Construct::from_slice(low)),
+    }
+}
+
+impl<T, U, S: Gamma> Construct<T, U, S> {
+    /// Project onto the lower level algebra `L`, fails if any of the discarded components is non-zero.
+    pub fn project<L: TryFrom<Self, Error=ProjectError>>(self) -> Result<L, ProjectError> {
+        L::try_from(self)
+    }
+}
+
+/// Implement embedding and mixed arithmetic between `L = Construct<T, U, W>` and `H = Construct<T, $V, S>`,
+/// where `$V` is either `L` or the higher level algebra that `L` is embedded into.
+macro_rules! impl_embed { ([$($G:ident),*], $V:ty) => (
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> From<Construct<T, U, W>> for Construct<T, $V, S> where $V: From<Construct<T, U, W>> + Zero {
+        fn from(x: Construct<T, U, W>) -> Self {
+            Self::new(<$V>::from(x), <$V>::zero())
+        }
+    }
+    impl<T: Zero + Clone, U, W: Gamma, S: Gamma, $($G: Gamma),*> TryFrom<Construct<T, $V, S>> for Construct<T, U, W> where
+        Self: Flat<T>,
+        Construct<T, $V, S>: Flat<T>,
+    {
+        type Error = ProjectError;
+        fn try_from(x: Construct<T, $V, S>) -> Result<Self, ProjectError> {
+            project::<T, U, W, _>(&x)
+        }
+    }
+
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Add<Construct<T, U, W>> for Construct<T, $V, S> where $V: Add<Construct<T, U, W>, Output=$V> {
+        type Output = Self;
+        fn add(self, other: Construct<T, U, W>) -> Self {
+            let (re, im) = self.split();
+            Self::new(re + other, im)
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Sub<Construct<T, U, W>> for Construct<T, $V, S> where $V: Sub<Construct<T, U, W>, Output=$V> {
+        type Output = Self;
+        fn sub(self, other: Construct<T, U, W>) -> Self {
+            let (re, im) = self.split();
+            Self::new(re - other, im)
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Add<Construct<T, $V, S>> for Construct<T, U, W> where Construct<T, $V, S>: Add<Self, Output=Construct<T, $V, S>> {
+        type Output = Construct<T, $V, S>;
+        fn add(self, other: Construct<T, $V, S>) -> Self::Output {
+            other + self
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Sub<Construct<T, $V, S>> for Construct<T, U, W> where
+        Construct<T, $V, S>: Neg<Output=Construct<T, $V, S>> + Add<Self, Output=Construct<T, $V, S>>,
+    {
+        type Output = Construct<T, $V, S>;
+        fn sub(self, other: Construct<T, $V, S>) -> Self::Output {
+            -other + self
+        }
+    }
+
+    /// `(a, b) (c, 0) = (a c, b c̄)`
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Mul<Construct<T, U, W>> for Construct<T, $V, S> where
+        Construct<T, U, W>: Conj + Clone,
+        $V: Mul<Construct<T, U, W>, Output=$V>,
+    {
+        type Output = Self;
+        fn mul(self, other: Construct<T, U, W>) -> Self {
+            let (re, im) = self.split();
+            Self::new(re * other.clone(), im * other.conj())
+        }
+    }
+    /// `(c, 0) (a, b) = (c a, b c)`
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Mul<Construct<T, $V, S>> for Construct<T, U, W> where
+        Self: Mul<$V, Output=$V> + Clone,
+        $V: Mul<Self, Output=$V>,
+    {
+        type Output = Construct<T, $V, S>;
+        fn mul(self, other: Construct<T, $V, S>) -> Self::Output {
+            let (re, im) = other.split();
+            Self::Output::new(self.clone() * re, im * self)
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Div<Construct<T, U, W>> for Construct<T, $V, S> where
+        Construct<T, U, W>: Inv<Output=Construct<T, U, W>>,
+        Self: Mul<Construct<T, U, W>, Output=Self>,
+    {
+        type Output = Self;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, other: Construct<T, U, W>) -> Self {
+            self * other.inv()
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> Div<Construct<T, $V, S>> for Construct<T, U, W> where
+        Construct<T, $V, S>: Inv<Output=Construct<T, $V, S>>,
+        Self: Mul<Construct<T, $V, S>, Output=Construct<T, $V, S>>,
+    {
+        type Output = Construct<T, $V, S>;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, other: Construct<T, $V, S>) -> Self::Output {
+            self * other.inv()
+        }
+    }
+
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> AddAssign<Construct<T, U, W>> for Construct<T, $V, S> where $V: AddAssign<Construct<T, U, W>> {
+        fn add_assign(&mut self, other: Construct<T, U, W>) {
+            *self.re_mut() += other;
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> SubAssign<Construct<T, U, W>> for Construct<T, $V, S> where $V: SubAssign<Construct<T, U, W>> {
+        fn sub_assign(&mut self, other: Construct<T, U, W>) {
+            *self.re_mut() -= other;
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> MulAssign<Construct<T, U, W>> for Construct<T, $V, S> where Self: Mul<Construct<T, U, W>, Output=Self> + Clone {
+        fn mul_assign(&mut self, other: Construct<T, U, W>) {
+            *self = self.clone() * other;
+        }
+    }
+    impl<T, U, W: Gamma, S: Gamma, $($G: Gamma),*> DivAssign<Construct<T, U, W>> for Construct<T, $V, S> where Self: Div<Construct<T, U, W>, Output=Self> + Clone {
+        fn div_assign(&mut self, other: Construct<T, U, W>) {
+            *self = self.clone() / other;
+        }
+    }
+) }
+
+// Each invocation handles the algebras whose levels differ by one more step.
+impl_embed!([], Construct<T, U, W>);
+impl_embed!([S1], Construct<T, Construct<T, U, W>, S1>);
+impl_embed!([S1, S2], Construct<T, Construct<T, Construct<T, U, W>, S1>, S2>);
+impl_embed!([S1, S2, S3], Construct<T, Construct<T, Construct<T, Construct<T, U, W>, S1>, S2>, S3>);
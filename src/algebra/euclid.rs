@@ -0,0 +1,112 @@
+use core::ops::{Mul, Sub, Rem};
+use num_traits::{Zero, Float, PrimInt, Signed};
+use super::{*, traits::*};
+
+
+/// Division with the quotient rounded to the nearest integer (ties away from zero).
+///
+/// For hypercomplex numbers each component is divided by the scalar separately.
+pub trait RoundDiv<T = Self> {
+    /// Divide and round the quotient.
+    fn round_div(self, other: T) -> Self;
+}
+
+macro_rules! derive_round_div_int { ($T:ident) => (
+    impl RoundDiv for $T {
+        fn round_div(self, other: Self) -> Self {
+            let (q, r) = (self / other, self % other);
+            if r.abs() < other.abs() - r.abs() {
+                q
+            } else if (self < 0) == (other < 0) {
+                q + 1
+            } else {
+                q - 1
+            }
+        }
+    }
+) }
+macro_rules! derive_round_div_float { ($T:ident) => (
+    impl RoundDiv for $T {
+        fn round_div(self, other: Self) -> Self {
+            Float::round(self / other)
+        }
+    }
+) }
+
+derive_round_div_int!(i8);
+derive_round_div_int!(i16);
+derive_round_div_int!(i32);
+derive_round_div_int!(i64);
+
+derive_round_div_float!(f32);
+derive_round_div_float!(f64);
+
+impl<T: Clone, U: RoundDiv<T>> RoundDiv<T> for Construct<T, U> {
+    fn round_div(self, other: T) -> Self {
+        let (re, im) = self.split();
+        Self::new(re.round_div(other.clone()), im.round_div(other))
+    }
+}
+
+
+impl<T, U> Construct<T, U> where
+    T: Clone,
+    Self: Clone + Conj + Mul<Output=Self> + Sub<Output=Self> + NormSqr<Output=T> + RoundDiv<T>
+{
+    /// Right division with remainder, returns `(q, r)` such that `self = q * other + r`.
+    ///
+    /// The quotient is `self * other⁻¹` with components rounded to the nearest integers.
+    /// For Gaussian integers this is the Euclidean division, i.e. `r.norm_sqr() <= other.norm_sqr() / 2`.
+    /// For Lipschitz quaternions only `r.norm_sqr() <= other.norm_sqr()` is guaranteed, see [`Hurwitz`] for the Euclidean case.
+    pub fn div_rem(self, other: Self) -> (Self, Self) {
+        let q = (self.clone() * other.clone().conj()).round_div(other.clone().norm_sqr());
+        let r = self - q.clone() * other;
+        (q, r)
+    }
+    /// Left division with remainder, returns `(q, r)` such that `self = other * q + r`.
+    ///
+    /// Coincides with [`div_rem`](Self::div_rem) for commutative algebras.
+    pub fn div_rem_left(self, other: Self) -> (Self, Self) {
+        let q = (other.clone().conj() * self.clone()).round_div(other.clone().norm_sqr());
+        let r = self - other * q.clone();
+        (q, r)
+    }
+    /// Quotient of the right Euclidean division, see [`div_rem`](Self::div_rem).
+    pub fn div_euclid(self, other: Self) -> Self {
+        self.div_rem(other).0
+    }
+}
+
+/// Remainder of the right division with rounded quotient, see [`Construct::div_rem`].
+impl<T, U> Rem for Construct<T, U> where
+    T: Clone,
+    Self: Clone + Conj + Mul<Output=Self> + Sub<Output=Self> + NormSqr<Output=T> + RoundDiv<T>
+{
+    type Output = Self;
+    fn rem(self, other: Self) -> Self::Output {
+        self.div_rem(other).1
+    }
+}
+
+impl<T: PrimInt + Signed + Algebra + RoundDiv> Complex<T> {
+    /// Greatest common divisor of Gaussian integers.
+    ///
+    /// The result is normalized to have positive real part and non-negative imaginary part,
+    /// the GCD of two zeros is zero.
+    pub fn gcd(self, other: Self) -> Self {
+        let (mut a, mut b) = (self, other);
+        while !b.is_zero() {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        if a.is_zero() {
+            return a;
+        }
+        // Multiply by a unit to move into the first quadrant.
+        while !(a.re() > T::zero() && a.im() >= T::zero()) {
+            a *= Self::i();
+        }
+        a
+    }
+}
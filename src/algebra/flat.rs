@@ -0,0 +1,233 @@
+//! Uniform access to scalar components of hypercomplex numbers.
+//!
+//! `Construct` is `#[repr(C)]`, so any hypercomplex number built over scalar `T`
+//! is laid out in memory exactly as `[T; N]` where `N` is its dimension.
+//! Components are ordered by the basis element number, e.g. `[w, x, y, z]` for quaternions.
+
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut},
+    ptr,
+    slice,
+};
+use super::{construct::*, gamma::Gamma};
+
+
+/// Hypercomplex number which is a flat sequence of scalars in memory.
+///
+/// # Safety
+///
+/// The implementor must have the same memory layout as `[T; Self::DIM]`.
+pub unsafe trait Flat<T>: Sized {
+    /// Level of the Cayley–Dickson construction, `1` for complex numbers, `2` for quaternions and so on.
+    const LEVEL: usize;
+    /// Number of scalar components, `2^LEVEL`.
+    const DIM: usize = 1 << Self::LEVEL;
+}
+
+unsafe impl<T, U, V: Gamma, S: Gamma> Flat<T> for Construct<T, Construct<T, U, V>, S> where Construct<T, U, V>: Flat<T> {
+    const LEVEL: usize = Construct::<T, U, V>::LEVEL + 1;
+}
+unsafe impl<T, S: Gamma> Flat<T> for Construct<T, T, S> {
+    const LEVEL: usize = 1;
+}
+
+
+/// Error returned when the slice length doesn't match the dimension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromSliceError {
+    expected: usize,
+    found: usize,
+}
+
+impl TryFromSliceError {
+    /// Required number of components.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+    /// Actual length of the slice.
+    pub fn found(&self) -> usize {
+        self.found
+    }
+}
+
+impl Display for TryFromSliceError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "expected slice of length {}, found {}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromSliceError {}
+
+
+pub(crate) fn as_slice<T, C: Flat<T>>(x: &C) -> &[T] {
+    debug_assert_eq!(mem::size_of::<C>(), C::DIM * mem::size_of::<T>());
+    unsafe { slice::from_raw_parts(x as *const C as *const T, C::DIM) }
+}
+pub(crate) fn as_mut_slice<T, C: Flat<T>>(x: &mut C) -> &mut [T] {
+    debug_assert_eq!(mem::size_of::<C>(), C::DIM * mem::size_of::<T>());
+    unsafe { slice::from_raw_parts_mut(x as *mut C as *mut T, C::DIM) }
+}
+pub(crate) fn into_array<T, C: Flat<T>, const N: usize>(x: C) -> [T; N] {
+    assert_eq!(N, C::DIM, "array length doesn't match the dimension");
+    let x = ManuallyDrop::new(x);
+    unsafe { ptr::read(&*x as *const C as *const [T; N]) }
+}
+pub(crate) fn from_array<T, C: Flat<T>, const N: usize>(a: [T; N]) -> C {
+    assert_eq!(N, C::DIM, "array length doesn't match the dimension");
+    let a = ManuallyDrop::new(a);
+    unsafe { ptr::read(&*a as *const [T; N] as *const C) }
+}
+
+impl<T, U, S: Gamma> Construct<T, U, S> where Self: Flat<T> {
+    /// View all scalar components as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        as_slice(self)
+    }
+    /// View all scalar components as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        as_mut_slice(self)
+    }
+    /// Iterate over scalar components.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+    /// Iterate over mutable references to scalar components.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Create from slice of scalar components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice is not equal to the dimension.
+    pub fn from_slice(s: &[T]) -> Self where T: Clone {
+        match Self::try_from(s) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Convert into array of scalar components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to the dimension.
+    pub fn to_array<const N: usize>(self) -> [T; N] {
+        into_array(self)
+    }
+    /// Create from array of scalar components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not equal to the dimension.
+    pub fn from_array<const N: usize>(a: [T; N]) -> Self {
+        from_array(a)
+    }
+}
+
+impl<T: Clone, U, S: Gamma> TryFrom<&[T]> for Construct<T, U, S> where Self: Flat<T> {
+    type Error = TryFromSliceError;
+    fn try_from(s: &[T]) -> Result<Self, Self::Error> {
+        if s.len() != Self::DIM {
+            return Err(TryFromSliceError { expected: Self::DIM, found: s.len() });
+        }
+        let mut value = MaybeUninit::<Self>::uninit();
+        let dst = value.as_mut_ptr() as *mut T;
+        for (i, x) in s.iter().enumerate() {
+            // If `clone` panics, already written components are leaked but not dropped.
+            unsafe { dst.add(i).write(x.clone()) };
+        }
+        Ok(unsafe { value.assume_init() })
+    }
+}
+
+/// Access scalar component by the number of the basis element.
+impl<T, U, S: Gamma> Index<usize> for Construct<T, U, S> where Self: Flat<T> {
+    type Output = T;
+    fn index(&self, n: usize) -> &T {
+        &self.as_slice()[n]
+    }
+}
+impl<T, U, S: Gamma> IndexMut<usize> for Construct<T, U, S> where Self: Flat<T> {
+    fn index_mut(&mut self, n: usize) -> &mut T {
+        &mut self.as_mut_slice()[n]
+    }
+}
+
+impl<'a, T, U, S: Gamma> IntoIterator for &'a Construct<T, U, S> where Construct<T, U, S>: Flat<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T, U, S: Gamma> IntoIterator for &'a mut Construct<T, U, S> where Construct<T, U, S>: Flat<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use crate::*;
+
+    #[test]
+    fn layout() {
+        let q = Quaternion::new2(1, 2, 3, 4);
+        assert_eq!(q.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(q.to_array(), [1, 2, 3, 4]);
+        assert_eq!(Quaternion::from_array([1, 2, 3, 4]), q);
+        assert_eq!((q[0], q[1], q[2], q[3]), (q.w(), q.x(), q.y(), q.z()));
+        let o = Octonion::new2(Complex::new(0, 1), Complex::new(2, 3), Complex::new(4, 5), Complex::new(6, 7));
+        for (i, x) in o.iter().enumerate() {
+            assert_eq!(*x, i);
+        }
+        assert_eq!(o.iter().count(), 8);
+    }
+
+    #[test]
+    fn mutation() {
+        let mut s = Sedenion::<f64>::from_slice(&[0.0; 16]);
+        for (i, x) in s.iter_mut().enumerate() {
+            *x = i as f64;
+        }
+        s[15] = -1.0;
+        for x in &mut s {
+            *x *= 2.0;
+        }
+        assert_eq!(s.im_ref().im_ref().im_ref().im(), -2.0);
+        assert_eq!(s.re_ref().re_ref().im_ref().re(), 4.0);
+        let mut c = Complex::new(1.0, 2.0);
+        c.as_mut_slice().swap(0, 1);
+        assert_eq!(c, Complex::new(2.0, 1.0));
+    }
+
+    #[test]
+    fn from_slice() {
+        let v = [1, -2, 3, -4];
+        assert_eq!(Quaternion::try_from(&v[..]), Ok(Quaternion::new2(1, -2, 3, -4)));
+        let e = Quaternion::<i32>::try_from(&v[..3]).unwrap_err();
+        assert_eq!((e.expected(), e.found()), (4, 3));
+        assert!(Complex::<i32>::try_from(&v[..]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_slice_length() {
+        Octonion::<i32>::from_slice(&[0; 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_array_length() {
+        let _: [i32; 2] = Quaternion::new2(1, 2, 3, 4).to_array();
+    }
+}
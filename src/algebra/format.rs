@@ -1,31 +1,46 @@
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
-use super::construct::*;
+use super::{construct::*, flat::Flat, gamma::Gamma};
 
 pub enum FmtType {
     Debug,
     Display
 }
 
-pub trait Format<T> {
-    fn level() -> usize;
+/// Hypercomplex number which could be formatted as `Name(c0, c1, ...)`.
+pub trait Format<T>: Flat<T> {
+    /// Value of `γ` used in the outermost doubling.
+    const GAMMA: i8;
+    /// Whether all inner doublings are standard (`γ = -1`).
+    const BASE_STANDARD: bool;
+
     fn write_content_debug(&self, f: &mut Formatter) -> FmtResult where T: Debug;
     fn write_content_display(&self, f: &mut Formatter) -> FmtResult where T: Display;
+    /// Name of the algebra, `None` if it has no own name and is written as `Construct{LEVEL}`.
+    fn name() -> Option<&'static str> {
+        match (Self::BASE_STANDARD, Self::GAMMA, Self::LEVEL) {
+            (true, -1, 1) => Some("Complex"),
+            (true, -1, 2) => Some("Quaternion"),
+            (true, -1, 3) => Some("Octonion"),
+            (true, -1, 4) => Some("Sedenion"),
+            (true, 0, 1) => Some("Dual"),
+            (true, 1, 1) => Some("SplitComplex"),
+            (true, 1, 2) => Some("SplitQuaternion"),
+            (true, 1, 3) => Some("SplitOctonion"),
+            _ => None,
+        }
+    }
     fn write_name(f: &mut Formatter) -> FmtResult {
-        match Self::level() {
-            0 => unreachable!(),
-            1 => write!(f, "Complex"),
-            2 => write!(f, "Quaternion"),
-            3 => write!(f, "Octonion"),
-            4 => write!(f, "Sedenion"),
-            n @ _ => write!(f, "Construct{}", n),
+        match Self::name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "Construct{}", Self::LEVEL),
         }
     }
 }
 
-impl<T, U> Format<T> for Construct<T, Construct<T, U>> where Construct<T, U>: Format<T> {
-    fn level() -> usize {
-        Construct::<T, U>::level() + 1
-    }
+impl<T, U, V: Gamma, S: Gamma> Format<T> for Construct<T, Construct<T, U, V>, S> where Construct<T, U, V>: Format<T> {
+    const GAMMA: i8 = S::GAMMA;
+    const BASE_STANDARD: bool = Construct::<T, U, V>::BASE_STANDARD && Construct::<T, U, V>::GAMMA == -1;
+
     fn write_content_debug(&self, f: &mut Formatter) -> FmtResult where T: Debug {
         self.re_ref().write_content_debug(f)?;
         write!(f, ", ")?;
@@ -37,10 +52,10 @@ impl<T, U> Format<T> for Construct<T, Construct<T, U>> where Construct<T, U>: Fo
         self.im_ref().write_content_display(f)
     }
 }
-impl<T> Format<T> for Construct<T, T> {
-    fn level() -> usize {
-        1
-    }
+impl<T, S: Gamma> Format<T> for Construct<T, T, S> {
+    const GAMMA: i8 = S::GAMMA;
+    const BASE_STANDARD: bool = true;
+
     fn write_content_debug(&self, f: &mut Formatter) -> FmtResult where T: Debug {
         write!(f, "{:?}, {:?}", self.re_ref(), self.im_ref())
     }
@@ -49,7 +64,7 @@ impl<T> Format<T> for Construct<T, T> {
     }
 }
 
-impl<T: Debug, U> Debug for Construct<T, U> where Self: Format<T> {
+impl<T: Debug, U, S: Gamma> Debug for Construct<T, U, S> where Self: Format<T> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         Self::write_name(f)?;
         write!(f, "(")?;
@@ -57,7 +72,7 @@ impl<T: Debug, U> Debug for Construct<T, U> where Self: Format<T> {
         write!(f, ")")
     }
 }
-impl<T: Display, U> Display for Construct<T, U> where Self: Format<T> {
+impl<T: Display, U, S: Gamma> Display for Construct<T, U, S> where Self: Format<T> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         Self::write_name(f)?;
         write!(f, "(")?;
@@ -84,4 +99,13 @@ mod tests {
         assert_eq!(format!("{:?}", q), "Quaternion(1, -2, 3, -4)");
         assert_eq!(format!("{}", q), "Quaternion(1, -2, 3, -4)");
     }
+
+    #[test]
+    fn split() {
+        assert_eq!(format!("{}", Dual::<i32>::new(1, 2)), "Dual(1, 2)");
+        assert_eq!(format!("{}", SplitComplex::<i32>::new(1, 2)), "SplitComplex(1, 2)");
+        assert_eq!(format!("{}", SplitQuaternion::<i32>::new2(1, 2, 3, 4)), "SplitQuaternion(1, 2, 3, 4)");
+        let x = Construct::<i32, SplitComplex<i32>>::new2(1, 2, 3, 4);
+        assert_eq!(format!("{}", x), "Construct2(1, 2, 3, 4)");
+    }
 }
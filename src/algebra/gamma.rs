@@ -0,0 +1,61 @@
+use core::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+
+/// Parameter `γ` of the generalized Cayley–Dickson construction.
+///
+/// The product of pairs is `(a, b) * (c, d) = (a c + γ d* b, d a + b c*)`,
+/// so the new imaginary unit squares to `γ`, and the squared norm is `|(a, b)|² = |a|² - γ |b|²`.
+pub trait Gamma: Copy + Default + PartialEq + Debug {
+    /// Value of `γ`.
+    const GAMMA: i8;
+    /// Compute `x + γ y`.
+    fn add_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X;
+    /// Compute `x - γ y`.
+    fn sub_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X;
+}
+
+/// `γ = -1`, the standard Cayley–Dickson construction: complex numbers, quaternions, octonions and so on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Elliptic;
+
+/// `γ = 0`, produces dual numbers from real ones.
+///
+/// Dual quaternions are not provided by this construction: the doubling of quaternions with `γ = 0`
+/// is not associative, so it is a different algebra. Use [`DualQuaternion`](crate::transform::DualQuaternion) instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Parabolic;
+
+/// `γ = +1`, produces split-complex numbers, split-quaternions (coquaternions) and split-octonions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hyperbolic;
+
+impl Gamma for Elliptic {
+    const GAMMA: i8 = -1;
+    fn add_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X {
+        x - y
+    }
+    fn sub_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X {
+        x + y
+    }
+}
+impl Gamma for Parabolic {
+    const GAMMA: i8 = 0;
+    fn add_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, _: X) -> X {
+        x
+    }
+    fn sub_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, _: X) -> X {
+        x
+    }
+}
+impl Gamma for Hyperbolic {
+    const GAMMA: i8 = 1;
+    fn add_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X {
+        x + y
+    }
+    fn sub_gamma<X: Add<Output=X> + Sub<Output=X>>(x: X, y: X) -> X {
+        x - y
+    }
+}
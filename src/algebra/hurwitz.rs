@@ -0,0 +1,158 @@
+use core::ops::{Neg, Add, Sub, Mul, Rem};
+use num_traits::{Zero, One, PrimInt};
+use super::{*, euclid::RoundDiv};
+
+
+/// Hurwitz quaternion, i.e. quaternion whose components are either all integers or all halves of odd integers.
+///
+/// Unlike Lipschitz quaternions (`Quaternion<T>` with integer `T`) Hurwitz quaternions form a Euclidean ring,
+/// so division with remainder always reduces the norm and the GCD is well-defined.
+///
+/// The value is stored as a quaternion with doubled components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hurwitz<T> {
+    double: Quaternion<T>,
+}
+
+impl<T: PrimInt + Algebra> Hurwitz<T> {
+    /// Create from doubled components, returns `None` if components have different parity.
+    pub fn from_double(double: Quaternion<T>) -> Option<Self> {
+        let two = T::one() + T::one();
+        let parity = |x: T| (x % two).is_zero();
+        let p = parity(double.w());
+        if parity(double.x()) == p && parity(double.y()) == p && parity(double.z()) == p {
+            Some(Self { double })
+        } else {
+            None
+        }
+    }
+    /// Get doubled components.
+    pub fn into_double(self) -> Quaternion<T> {
+        self.double
+    }
+    /// Convert to Lipschitz quaternion, returns `None` if components are halves of odd integers.
+    pub fn into_lipschitz(self) -> Option<Quaternion<T>> {
+        let two = T::one() + T::one();
+        if (self.double.w() % two).is_zero() {
+            Some(self.double / two)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: PrimInt + Algebra> From<Quaternion<T>> for Hurwitz<T> {
+    fn from(lipschitz: Quaternion<T>) -> Self {
+        Self { double: lipschitz * (T::one() + T::one()) }
+    }
+}
+
+impl<T: PrimInt + Algebra> Conj for Hurwitz<T> {
+    fn conj(self) -> Self {
+        Self { double: self.double.conj() }
+    }
+}
+impl<T: PrimInt + Algebra> NormSqr for Hurwitz<T> {
+    type Output = T;
+    fn norm_sqr(self) -> T {
+        let two = T::one() + T::one();
+        self.double.norm_sqr() / (two * two)
+    }
+}
+
+impl<T: PrimInt + Algebra> Neg for Hurwitz<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { double: -self.double }
+    }
+}
+impl<T: PrimInt + Algebra> Add for Hurwitz<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self { double: self.double + other.double }
+    }
+}
+impl<T: PrimInt + Algebra> Sub for Hurwitz<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self { double: self.double - other.double }
+    }
+}
+impl<T: PrimInt + Algebra> Mul for Hurwitz<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self { double: (self.double * other.double) / (T::one() + T::one()) }
+    }
+}
+
+impl<T: PrimInt + Algebra> Zero for Hurwitz<T> {
+    fn zero() -> Self {
+        Self { double: Quaternion::zero() }
+    }
+    fn is_zero(&self) -> bool {
+        self.double.is_zero()
+    }
+}
+impl<T: PrimInt + Algebra> One for Hurwitz<T> {
+    fn one() -> Self {
+        Quaternion::one().into()
+    }
+}
+
+impl<T: PrimInt + Algebra> Associative for Hurwitz<T> {}
+impl<T: PrimInt + Algebra> Alternative for Hurwitz<T> {}
+
+impl<T: PrimInt + Algebra + RoundDiv> Hurwitz<T> {
+    /// Choose the quotient for `x / n` (in doubled components) giving the smallest remainder `rem(q)`.
+    fn round_quotient<F: Fn(Self) -> Self>(x: Quaternion<T>, n: T, rem: F) -> (Self, Self) {
+        let two = T::one() + T::one();
+        // Nearest Lipschitz quaternion.
+        let lq = Self { double: x.round_div(n) * two };
+        // Nearest quaternion with half-odd components.
+        let nn = Quaternion::new2(n, n, n, n);
+        let ones = Quaternion::new2(T::one(), T::one(), T::one(), T::one());
+        let hq = Self { double: (x * two - nn).round_div(n * two) * two + ones };
+        let (lr, hr) = (rem(lq), rem(hq));
+        if lr.norm_sqr() <= hr.norm_sqr() {
+            (lq, lr)
+        } else {
+            (hq, hr)
+        }
+    }
+    /// Right Euclidean division, returns `(q, r)` such that `self = q * other + r` and `r.norm_sqr() <= other.norm_sqr() / 2`.
+    pub fn div_rem(self, other: Self) -> (Self, Self) {
+        let x = self.double * other.double.conj();
+        let n = other.double.norm_sqr();
+        Self::round_quotient(x, n, |q| self - q * other)
+    }
+    /// Left Euclidean division, returns `(q, r)` such that `self = other * q + r` and `r.norm_sqr() <= other.norm_sqr() / 2`.
+    pub fn div_rem_left(self, other: Self) -> (Self, Self) {
+        let x = other.double.conj() * self.double;
+        let n = other.double.norm_sqr();
+        Self::round_quotient(x, n, |q| self - other * q)
+    }
+    /// Quotient of the right Euclidean division, see [`div_rem`](Self::div_rem).
+    pub fn div_euclid(self, other: Self) -> Self {
+        self.div_rem(other).0
+    }
+    /// Greatest common right divisor, i.e. the generator of the left ideal spanned by `self` and `other`.
+    ///
+    /// The result is defined up to multiplication by one of 24 units from the left.
+    pub fn gcd(self, other: Self) -> Self {
+        let (mut a, mut b) = (self, other);
+        while !b.is_zero() {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+
+/// Remainder of the right Euclidean division, see [`Hurwitz::div_rem`].
+impl<T: PrimInt + Algebra + RoundDiv> Rem for Hurwitz<T> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(other).1
+    }
+}
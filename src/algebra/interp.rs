@@ -0,0 +1,179 @@
+//! Interpolation and averaging of unit quaternions.
+//!
+//! All functions take the double cover into account: `q` and `-q` represent the same rotation,
+//! so the shortest arc between rotations is used unless stated otherwise.
+
+use num_traits::{Float, Inv, One, Zero};
+use super::*;
+
+
+/// Flip `b` to the hemisphere of `a`.
+fn align<T: Float + Algebra + Dot<Output=T>>(a: Quaternion<T>, b: Quaternion<T>) -> Quaternion<T> {
+    if a.dot(b) < T::zero() { -b } else { b }
+}
+
+/// Spherical linear interpolation without choosing the shortest arc.
+///
+/// For opposite quaternions the arc is not unique, then it goes through `a * i`.
+fn slerp_arc<T: Float + Algebra + Dot<Output=T>>(a: Quaternion<T>, b: Quaternion<T>, t: T) -> Quaternion<T> {
+    let d = a.dot(b);
+    let tol = T::epsilon().sqrt();
+    if d > T::one() - tol {
+        // Quaternions are too close, fall back to linear interpolation.
+        return (a * (T::one() - t) + b * t).normalize();
+    }
+    if d < tol - T::one() {
+        // Quaternions are nearly opposite, so move along the component of `b` orthogonal to `a` instead of dividing by `sin(theta)`.
+        // The difference is small, so it is orthogonalized twice to cancel the rounding errors.
+        let u = b - a * d;
+        let u = u - a * a.dot(u);
+        let n = u.norm();
+        let u = if n > T::zero() { u / n } else { a * Quaternion::i() };
+        let theta = n.atan2(d);
+        return a * (t * theta).cos() + u * (t * theta).sin();
+    }
+    let theta = d.acos();
+    (a * ((T::one() - t) * theta).sin() + b * (t * theta).sin()) / theta.sin()
+}
+
+/// Spherical linear interpolation between unit quaternions along the shortest arc.
+///
+/// Returns `a` for `t == 0` and `±b` for `t == 1`, the angular velocity is constant.
+pub fn slerp<T: Float + Algebra + Dot<Output=T>>(a: Quaternion<T>, b: Quaternion<T>, t: T) -> Quaternion<T> {
+    slerp_arc(a, align(a, b), t)
+}
+
+/// Normalized linear interpolation between unit quaternions along the shortest arc.
+///
+/// Follows the same path as [`slerp`] but with non-constant angular velocity. It is much cheaper to compute.
+pub fn nlerp<T: Float + Algebra + Dot<Output=T>>(a: Quaternion<T>, b: Quaternion<T>, t: T) -> Quaternion<T> {
+    (a * (T::one() - t) + align(a, b) * t).normalize()
+}
+
+/// Compute the intermediate control point of [`squad`] for `q` having neighbours `prev` and `next` in the sequence.
+///
+/// `s = q * exp(-(ln(q⁻¹ * next) + ln(q⁻¹ * prev)) / 4)`
+pub fn squad_control<T: Float + Algebra + Dot<Output=T>>(prev: Quaternion<T>, q: Quaternion<T>, next: Quaternion<T>) -> Quaternion<T> {
+    let qi = q.inv();
+    let (prev, next) = (align(q, prev), align(q, next));
+    let four = T::from(4).unwrap();
+    q * (-((qi * next).ln() + (qi * prev).ln()) / four).exp()
+}
+
+/// Spherical quadrangle interpolation between `a` and `b` with control points `sa` and `sb`.
+///
+/// Sequence of keyframes interpolated with control points computed by [`squad_control`] gives a path with continuous angular velocity.
+/// Adjacent keyframes are expected to be in the same hemisphere, i.e. `a.dot(b) >= 0`.
+pub fn squad<T: Float + Algebra + Dot<Output=T>>(a: Quaternion<T>, sa: Quaternion<T>, sb: Quaternion<T>, b: Quaternion<T>, t: T) -> Quaternion<T> {
+    let two = T::one() + T::one();
+    slerp_arc(slerp_arc(a, b, t), slerp_arc(sa, sb, t), two * t * (T::one() - t))
+}
+
+/// Blend unit quaternions in the tangent space of the first one:
+///
+/// `q0 * exp(Σ wᵢ ln(q0⁻¹ * qᵢ))`
+///
+/// For two quaternions it coincides with [`slerp`]. Weights are expected to sum to one.
+///
+/// # Panics
+///
+/// Panics if `quats.len() != weights.len()`.
+pub fn log_blend<T: Float + Algebra + Dot<Output=T>>(quats: &[Quaternion<T>], weights: &[T]) -> Quaternion<T> {
+    assert_eq!(quats.len(), weights.len(), "number of weights doesn't match the number of quaternions");
+    let base = match quats.first() {
+        Some(q) => *q,
+        None => return Quaternion::one(),
+    };
+    let bi = base.inv();
+    let sum = quats.iter().zip(weights.iter()).fold(Quaternion::<T>::zero(), |acc, (&q, &w)| {
+        acc + (bi * align(base, q)).ln() * w
+    });
+    base * sum.exp()
+}
+
+/// Weighted average of unit quaternions using Markley's method.
+///
+/// The result is the eigenvector of `M = Σ wᵢ qᵢ qᵢᵀ` with the largest eigenvalue,
+/// i.e. the rotation minimizing the weighted sum of squared Frobenius distances between rotation matrices.
+/// The sign of the result is chosen to be in the hemisphere of the first quaternion.
+///
+/// F. Landis Markley et al., "Averaging Quaternions" (2007).
+///
+/// # Panics
+///
+/// Panics if `quats.len() != weights.len()`.
+pub fn average<T: Float + Algebra + Dot<Output=T>>(quats: &[Quaternion<T>], weights: &[T]) -> Quaternion<T> {
+    assert_eq!(quats.len(), weights.len(), "number of weights doesn't match the number of quaternions");
+    let mut m = [[T::zero(); 4]; 4];
+    for (q, &w) in quats.iter().zip(weights.iter()) {
+        let c = [q.w(), q.x(), q.y(), q.z()];
+        for i in 0..4 {
+            for j in 0..4 {
+                m[i][j] = m[i][j] + w * c[i] * c[j];
+            }
+        }
+    }
+    let v = max_eigenvector(m);
+    let q = Quaternion::new2(v[0], v[1], v[2], v[3]).normalize();
+    match quats.first() {
+        Some(&first) => align(first, q),
+        None => Quaternion::one(),
+    }
+}
+
+/// Find the eigenvector of symmetric 4x4 matrix with the largest eigenvalue using Jacobi rotations.
+fn max_eigenvector<T: Float>(mut a: [[T; 4]; 4]) -> [T; 4] {
+    let mut v = [[T::zero(); 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    let two = T::one() + T::one();
+    for _ in 0..32 {
+        let (mut off, mut diag) = (T::zero(), T::zero());
+        for (i, row) in a.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                if i == j {
+                    diag = diag + x * x;
+                } else {
+                    off = off + x * x;
+                }
+            }
+        }
+        if off <= T::epsilon() * T::epsilon() * diag {
+            break;
+        }
+        for p in 0..4 {
+            for q in (p + 1)..4 {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = (t * t + T::one()).sqrt().recip();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (rp, rq) = (a[p], a[q]);
+                for (k, (&pk, &qk)) in rp.iter().zip(rq.iter()).enumerate() {
+                    a[p][k] = c * pk - s * qk;
+                    a[q][k] = s * pk + c * qk;
+                }
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    let mut best = 0;
+    for i in 1..4 {
+        if a[i][i] > a[best][best] {
+            best = i;
+        }
+    }
+    [v[0][best], v[1][best], v[2][best], v[3][best]]
+}
@@ -1,5 +1,6 @@
 use super::*;
-use num_traits::{Float};
+use num_traits::{Float, Zero};
+use super::flat::Flat;
 use num_complex::{Complex as NumComplex};
 
 
@@ -118,3 +119,92 @@ impl<T: Float + Clone> Complex<T> where Self: Norm<Output=T> {
         Self::new(T::zero(), theta).exp() * r
     }
 }
+
+impl<T: Float> Transcendental<T> for Complex<T> {
+    fn exp(self) -> Self { Complex::exp(self) }
+    fn ln(self) -> Self { Complex::ln(self) }
+    fn sqrt(self) -> Self { Complex::sqrt(self) }
+    fn powf(self, exp: T) -> Self { Complex::powf(self, exp) }
+    fn sin(self) -> Self { Complex::sin(self) }
+    fn cos(self) -> Self { Complex::cos(self) }
+    fn tan(self) -> Self { Complex::tan(self) }
+    fn asin(self) -> Self { Complex::asin(self) }
+    fn acos(self) -> Self { Complex::acos(self) }
+    fn atan(self) -> Self { Complex::atan(self) }
+    fn sinh(self) -> Self { Complex::sinh(self) }
+    fn cosh(self) -> Self { Complex::cosh(self) }
+    fn tanh(self) -> Self { Complex::tanh(self) }
+    fn asinh(self) -> Self { Complex::asinh(self) }
+    fn acosh(self) -> Self { Complex::acosh(self) }
+    fn atanh(self) -> Self { Complex::atanh(self) }
+}
+
+impl<T, U> Construct<T, Construct<T, U>> where
+    T: Float + Algebra,
+    Self: Algebra<T> + Clone + Norm<Output=T> + Flat<T>,
+{
+    /// Get the complex counterpart `a + i|v|` and the unit axis `u`.
+    fn split_axis(self) -> (Complex<T>, Self) {
+        let re = self[0];
+        let v = self - re;
+        let vn = v.clone().norm();
+        let axis = if vn > T::zero() {
+            v / vn
+        } else {
+            let mut e = Self::zero();
+            e[1] = T::one();
+            e
+        };
+        (Complex::new(re, vn), axis)
+    }
+    /// Apply function in the complex plane containing `self`.
+    fn map_complex<F: FnOnce(Complex<T>) -> Complex<T>>(self, f: F) -> Self {
+        let (c, axis) = self.split_axis();
+        let (re, im) = f(c).split();
+        axis * im + re
+    }
+    /// Calculate the angle between `self` and the real axis, lies in `[0, π]`.
+    pub fn arg(self) -> T {
+        self.split_axis().0.arg()
+    }
+}
+
+/// Transcendental functions of quaternions, octonions and higher Cayley–Dickson algebras.
+///
+/// Any element `q = a + v` lies in the complex plane spanned by `1` and the unit axis `u = v/|v|`.
+/// So the functions are computed by applying the corresponding complex function to `a + i|v|`
+/// and mapping the result back with `i -> u`.
+///
+/// Because `|v| >= 0`, branch cuts are those of the complex functions, taken from the upper half-plane side.
+/// On the real axis the axis is undefined, and the first imaginary unit `e1` is used instead.
+/// E.g. `Quaternion::new2(-1.0, 0.0, 0.0, 0.0).ln()` is `πi`.
+impl<T, U> Transcendental<T> for Construct<T, Construct<T, U>> where
+    T: Float + Algebra,
+    Self: Algebra<T> + Clone + Norm<Output=T> + Flat<T>,
+{
+    fn exp(self) -> Self { self.map_complex(Complex::exp) }
+    fn ln(self) -> Self { self.map_complex(Complex::ln) }
+    fn sqrt(self) -> Self { self.map_complex(Complex::sqrt) }
+    fn powf(self, exp: T) -> Self { self.map_complex(|c| c.powf(exp)) }
+    fn sin(self) -> Self { self.map_complex(Complex::sin) }
+    fn cos(self) -> Self { self.map_complex(Complex::cos) }
+    fn tan(self) -> Self { self.map_complex(Complex::tan) }
+    fn asin(self) -> Self { self.map_complex(Complex::asin) }
+    fn acos(self) -> Self { self.map_complex(Complex::acos) }
+    fn atan(self) -> Self { self.map_complex(Complex::atan) }
+    fn sinh(self) -> Self { self.map_complex(Complex::sinh) }
+    fn cosh(self) -> Self { self.map_complex(Complex::cosh) }
+    fn tanh(self) -> Self { self.map_complex(Complex::tanh) }
+    fn asinh(self) -> Self { self.map_complex(Complex::asinh) }
+    fn acosh(self) -> Self { self.map_complex(Complex::acosh) }
+    fn atanh(self) -> Self { self.map_complex(Complex::atanh) }
+}
+
+impl<T: Float + Algebra> Quaternion<T> {
+    /// Raises `self` to a quaternion power, computed as `e^(ln(self) * exp)`.
+    ///
+    /// Note that the order of multiplication matters unless `self` and `exp` commute.
+    pub fn powq(self, exp: Self) -> Self {
+        (self.ln() * exp).exp()
+    }
+}
@@ -1,9 +1,18 @@
 mod traits;
+mod gamma;
 mod construct;
 mod second_order;
 mod math;
 mod specific;
+mod euclid;
+mod hurwitz;
+mod rotation;
+mod representation;
 pub mod format;
+pub mod flat;
+pub mod embed;
+pub mod parse;
+pub mod interp;
 
 #[cfg(feature = "random")]
 pub mod random;
@@ -11,14 +20,27 @@ pub mod random;
 #[cfg(feature = "approx")]
 pub mod approx;
 
+#[cfg(feature = "serde")]
+pub mod serialize;
+
+#[cfg(feature = "simd")]
+pub mod simd;
+
+#[cfg(feature = "alloc")]
+pub mod batch;
+
 #[cfg(all(test, feature = "random", feature = "approx"))]
 mod tests;
 
 
 use num_complex::{Complex as NumComplex};
 
-pub use traits::{Conj, Dot, NormSqr, Norm, NormL1, Algebra};
+pub use traits::{Conj, Dot, NormSqr, Norm, NormL1, Transcendental, Algebra, Commutative, Associative, Alternative, DivisionAlgebra};
+pub use gamma::{Gamma, Elliptic, Parabolic, Hyperbolic};
 pub use construct::{Construct};
+pub use euclid::{RoundDiv};
+pub use hurwitz::{Hurwitz};
+pub use rotation::{EulerSeq, EulerFrame};
 
 
 /// 2-dimensional commutative and associative algebra.
@@ -33,15 +55,27 @@ pub type Octonion<T> = Construct<T, Quaternion<T>>;
 /// 16-dimensional non-commutative and non-associative algebra with nontrivial zero divisors.
 pub type Sedenion<T> = Construct<T, Octonion<T>>;
 
+/// Dual numbers `a + bε`, where `ε² = 0`.
+pub type Dual<T> = Construct<T, T, Parabolic>;
+
+/// Split-complex numbers `a + bj`, where `j² = 1`.
+pub type SplitComplex<T> = Construct<T, T, Hyperbolic>;
+
+/// Split-quaternions (coquaternions), 4-dimensional associative algebra with nontrivial zero divisors.
+pub type SplitQuaternion<T> = Construct<T, Complex<T>, Hyperbolic>;
+
+/// Split-octonions, 8-dimensional alternative algebra with nontrivial zero divisors.
+pub type SplitOctonion<T> = Construct<T, Quaternion<T>, Hyperbolic>;
+
 
 impl<T> From<NumComplex<T>> for Complex<T> {
     fn from(other: NumComplex<T>) -> Self {
         Self::new(other.re, other.im)
     }
 }
-impl<T> Into<NumComplex<T>> for Complex<T> {
-    fn into(self) -> NumComplex<T> {
-        let (re, im) = self.split();
+impl<T> From<Complex<T>> for NumComplex<T> {
+    fn from(other: Complex<T>) -> Self {
+        let (re, im) = other.split();
         NumComplex { re, im }
     }
 }
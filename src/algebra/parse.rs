@@ -0,0 +1,397 @@
+//! Parsing hypercomplex numbers from strings.
+//!
+//! Two notations are accepted:
+//!
+//! + Sum of terms, e.g. `1-2i`, `1 + 2i - 3j + 4k` or `1+2e1-3e5+4e7`.
+//!   Each term is an optional scalar coefficient followed by an optional basis unit.
+//!   Units `i`, `j` and `k` are aliases for `e1`, `e2` and `e3`, a term without a unit is `e0`.
+//!   Coefficient and unit may be separated by `*`, e.g. `2*e1`.
+//!   This is required when the radix is large enough for unit letters to be digits,
+//!   and when a decimal coefficient written in scientific notation is followed by a unit.
+//! + The output of `Display`, e.g. `Quaternion(1, -2, 3, -4)`.
+//!   The name must match the parsed type, including the signs of its doublings,
+//!   e.g. `Dual(1, 2)` is not accepted as `Complex`.
+//!   Algebras without their own name are written as `Construct{level}`.
+
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Neg, IndexMut},
+};
+use num_traits::{Num, Zero};
+use super::format::Format;
+
+
+/// Kind of error that occurred while parsing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Input is empty or contains only whitespace.
+    Empty,
+    /// Unexpected character or end of input.
+    Unexpected,
+    /// Scalar coefficient cannot be parsed.
+    InvalidScalar,
+    /// Name before parentheses is not a known algebra name.
+    UnknownName,
+    /// Name before parentheses denotes an algebra of another level.
+    LevelMismatch,
+    /// Name before parentheses denotes another algebra of the same level, e.g. `Dual` instead of `Complex`.
+    GammaMismatch,
+    /// Number of components in parentheses doesn't match the dimension.
+    ComponentCount,
+    /// Basis unit index exceeds the dimension.
+    UnitOutOfRange,
+    /// The same basis unit occurs more than once.
+    DuplicateUnit,
+}
+
+/// Error of parsing a hypercomplex number, contains the byte position in the input where it occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    pos: usize,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, pos: usize) -> Self {
+        Self { kind, pos }
+    }
+    /// Kind of the error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+    /// Byte position in the input string.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let desc = match self.kind {
+            ParseErrorKind::Empty => "empty input",
+            ParseErrorKind::Unexpected => "unexpected character or end of input",
+            ParseErrorKind::InvalidScalar => "invalid scalar",
+            ParseErrorKind::UnknownName => "unknown algebra name",
+            ParseErrorKind::LevelMismatch => "algebra name doesn't match the level of the type",
+            ParseErrorKind::GammaMismatch => "algebra name doesn't match the type",
+            ParseErrorKind::ComponentCount => "wrong number of components",
+            ParseErrorKind::UnitOutOfRange => "basis unit is out of range",
+            ParseErrorKind::DuplicateUnit => "duplicate basis unit",
+        };
+        write!(f, "{} at position {}", desc, self.pos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    radix: u32,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.pos)
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Parse the whole input.
+    fn parse<T, C>(&mut self) -> Result<C, ParseError> where T: Num + Neg<Output=T>, C: Format<T> + Zero + IndexMut<usize, Output=T> {
+        self.skip_ws();
+        if self.peek().is_none() {
+            return Err(self.error(ParseErrorKind::Empty));
+        }
+        let value = if self.is_named() {
+            self.parse_named()?
+        } else {
+            self.parse_sum()?
+        };
+        self.skip_ws();
+        match self.peek() {
+            None => Ok(value),
+            Some(_) => Err(self.error(ParseErrorKind::Unexpected)),
+        }
+    }
+
+    /// Check whether the input is in `Name(...)` notation.
+    fn is_named(&self) -> bool {
+        let rest = self.rest();
+        let name_len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+        name_len > 1 &&
+        rest[..1].chars().all(|c| c.is_ascii_alphabetic()) &&
+        rest[name_len..].trim_start().starts_with('(')
+    }
+
+    fn parse_named<T, C>(&mut self) -> Result<C, ParseError> where T: Num, C: Format<T> + Zero + IndexMut<usize, Output=T> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric()) {
+            self.bump();
+        }
+        let name = &self.src[start..self.pos];
+        let level = match name {
+            "Complex" | "Dual" | "SplitComplex" => 1,
+            "Quaternion" | "SplitQuaternion" => 2,
+            "Octonion" | "SplitOctonion" => 3,
+            "Sedenion" => 4,
+            _ => match name.strip_prefix("Construct").and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n > 0 => n,
+                _ => return Err(ParseError::new(ParseErrorKind::UnknownName, start)),
+            },
+        };
+        if level != C::LEVEL {
+            return Err(ParseError::new(ParseErrorKind::LevelMismatch, start));
+        }
+        let matches = match C::name() {
+            Some(own) => name == own,
+            None => name.starts_with("Construct"),
+        };
+        if !matches {
+            return Err(ParseError::new(ParseErrorKind::GammaMismatch, start));
+        }
+        self.skip_ws();
+        self.eat('(');
+
+        let mut value = C::zero();
+        let mut count = 0;
+        loop {
+            self.skip_ws();
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c != ',' && c != ')') {
+                self.bump();
+            }
+            if self.peek().is_none() {
+                return Err(self.error(ParseErrorKind::Unexpected));
+            }
+            if count >= C::DIM {
+                return Err(ParseError::new(ParseErrorKind::ComponentCount, start));
+            }
+            let text = self.src[start..self.pos].trim_end();
+            value[count] = T::from_str_radix(text, self.radix)
+                .map_err(|_| ParseError::new(ParseErrorKind::InvalidScalar, start))?;
+            count += 1;
+            if self.eat(')') {
+                break;
+            }
+            self.bump();
+        }
+        if count != C::DIM {
+            return Err(self.error(ParseErrorKind::ComponentCount));
+        }
+        Ok(value)
+    }
+
+    fn parse_sum<T, C>(&mut self) -> Result<C, ParseError> where T: Num + Neg<Output=T>, C: Format<T> + Zero + IndexMut<usize, Output=T> {
+        let mut value = C::zero();
+        // Bit mask of units already seen, units above 63 are not tracked.
+        let mut seen: u64 = 0;
+        let mut first = true;
+        loop {
+            self.skip_ws();
+            if !first && self.peek().is_none() {
+                break;
+            }
+            let negative = match self.peek() {
+                Some('+') => { self.bump(); false },
+                Some('-') => { self.bump(); true },
+                _ if first => false,
+                _ => return Err(self.error(ParseErrorKind::Unexpected)),
+            };
+            first = false;
+            self.skip_ws();
+
+            let term_pos = self.pos;
+            let coef = self.parse_coef::<T>()?;
+            self.skip_ws();
+            let star = self.eat('*');
+            if star {
+                self.skip_ws();
+            }
+            let unit_pos = self.pos;
+            let unit = match self.parse_unit() {
+                Some(n) => n,
+                None if coef.is_some() && !star => 0,
+                None => return Err(self.error(ParseErrorKind::Unexpected)),
+            };
+            if unit >= C::DIM {
+                return Err(ParseError::new(ParseErrorKind::UnitOutOfRange, unit_pos));
+            }
+            if unit < 64 {
+                if seen & (1 << unit) != 0 {
+                    return Err(ParseError::new(ParseErrorKind::DuplicateUnit, term_pos));
+                }
+                seen |= 1 << unit;
+            }
+            let coef = coef.unwrap_or_else(T::one);
+            value[unit] = if negative { -coef } else { coef };
+        }
+        Ok(value)
+    }
+
+    /// Parse scalar coefficient if present.
+    fn parse_coef<T: Num>(&mut self) -> Result<Option<T>, ParseError> {
+        let start = self.pos;
+        let radix = self.radix;
+        while let Some(c) = self.peek() {
+            if c.is_digit(radix) || c == '.' {
+                self.bump();
+            } else if radix == 10 && (c == 'e' || c == 'E') && self.is_exponent() {
+                self.bump();
+                self.eat('+');
+                self.eat('-');
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return Ok(None);
+        }
+        T::from_str_radix(&self.src[start..self.pos], radix)
+            .map(Some)
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidScalar, start))
+    }
+
+    /// Check whether the `e` or `E` at the current position starts a decimal exponent.
+    /// Lowercase `e` followed by a digit is a basis unit.
+    fn is_exponent(&self) -> bool {
+        let c = self.peek();
+        match self.peek_nth(1) {
+            Some('+') | Some('-') => self.peek_nth(2).is_some_and(|d| d.is_ascii_digit()),
+            Some(d) => c == Some('E') && d.is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    /// Parse basis unit if present and return its index.
+    fn parse_unit(&mut self) -> Option<usize> {
+        let n = match self.peek()? {
+            'i' => 1,
+            'j' => 2,
+            'k' => 3,
+            'e' => {
+                let digits = self.rest()[1..].find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest().len() - 1);
+                let n = self.rest()[1..(1 + digits)].parse::<usize>().ok()?;
+                self.pos += digits;
+                n
+            },
+            _ => return None,
+        };
+        self.bump();
+        Some(n)
+    }
+}
+
+/// Parse hypercomplex number from string with the specified radix.
+pub fn parse<T, C>(src: &str, radix: u32) -> Result<C, ParseError> where T: Num + Neg<Output=T>, C: Format<T> + Zero + IndexMut<usize, Output=T> {
+    Parser { src, pos: 0, radix }.parse()
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::string::ToString;
+    use num_traits::Num;
+    use crate::algebra::*;
+    use super::{ParseError, ParseErrorKind};
+
+    #[test]
+    fn complex() {
+        assert_eq!("1+2i".parse(), Ok(Complex::<i32>::new(1, 2)));
+        assert_eq!(" -1 - i ".parse(), Ok(Complex::<i32>::new(-1, -1)));
+        assert_eq!("3i".parse(), Ok(Complex::<i32>::new(0, 3)));
+        assert_eq!("2.5-0.5i".parse(), Ok(Complex::<f64>::new(2.5, -0.5)));
+        assert_eq!("1e-3+1E2i".parse(), Ok(Complex::<f64>::new(1e-3, 1e2)));
+        assert_eq!(Complex::<i32>::from_str_radix("ff-10*i", 16), Ok(Complex::new(255, -16)));
+    }
+
+    #[test]
+    fn quaternion() {
+        assert_eq!("1 + 2i - 3j + 4k".parse(), Ok(Quaternion::<i32>::new2(1, 2, -3, 4)));
+        assert_eq!("k+1".parse(), Ok(Quaternion::<i32>::new2(1, 0, 0, 1)));
+        assert_eq!("2e1 - 2*e3".parse(), Ok(Quaternion::<i32>::new2(0, 2, 0, -2)));
+    }
+
+    #[test]
+    fn octonion() {
+        let o: Octonion<i32> = "1-e1+2e5+3e7".parse().unwrap();
+        assert_eq!(o, Octonion::new2(
+            Complex::new(1, -1), Complex::new(0, 0),
+            Complex::new(0, 2), Complex::new(0, 3),
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let q = Quaternion::<i32>::new2(1, -2, 3, -4);
+        assert_eq!(q.to_string().parse(), Ok(q));
+        let o = Octonion::<f64>::new2(
+            Complex::new(1.5, -2.0), Complex::new(0.0, 3.0),
+            Complex::new(-0.25, 2.0), Complex::new(1e10, 1e-10),
+        );
+        assert_eq!(o.to_string().parse(), Ok(o));
+    }
+
+    #[test]
+    fn display_gamma() {
+        let d = Dual::<i32>::new(1, -2);
+        assert_eq!(d.to_string().parse(), Ok(d));
+        let s = SplitComplex::<i32>::new(1, -2);
+        assert_eq!(s.to_string().parse(), Ok(s));
+        let q = SplitQuaternion::<i32>::new2(1, -2, 3, -4);
+        assert_eq!(q.to_string().parse(), Ok(q));
+        let o = SplitOctonion::<i32>::new2(Complex::new(1, 2), Complex::new(3, 4), Complex::new(5, 6), Complex::new(7, 8));
+        assert_eq!(o.to_string().parse(), Ok(o));
+        let x = Construct::<i32, SplitComplex<i32>>::new2(1, 2, 3, 4);
+        assert_eq!(x.to_string(), "Construct2(1, 2, 3, 4)");
+        assert_eq!(x.to_string().parse(), Ok(x));
+    }
+
+    #[test]
+    fn errors() {
+        fn err<C>(kind: ParseErrorKind, pos: usize) -> Result<C, ParseError> {
+            Err(ParseError::new(kind, pos))
+        }
+        assert_eq!("  ".parse::<Complex<i32>>(), err(ParseErrorKind::Empty, 2));
+        assert_eq!("1+j".parse::<Complex<i32>>(), err(ParseErrorKind::UnitOutOfRange, 2));
+        assert_eq!("1+2i+3i".parse::<Complex<i32>>(), err(ParseErrorKind::DuplicateUnit, 5));
+        assert_eq!("1 2i".parse::<Complex<i32>>(), err(ParseErrorKind::Unexpected, 2));
+        assert_eq!("1+".parse::<Complex<i32>>(), err(ParseErrorKind::Unexpected, 2));
+        assert_eq!("1.5".parse::<Complex<i32>>(), err(ParseErrorKind::InvalidScalar, 0));
+        assert_eq!("Complex(1, 2)".parse::<Quaternion<i32>>(), err(ParseErrorKind::LevelMismatch, 0));
+        assert_eq!("Quaternion(1, 2, 3)".parse::<Quaternion<i32>>(), err(ParseErrorKind::ComponentCount, 19));
+        assert_eq!("Quaternion(1, 2, x, 4)".parse::<Quaternion<i32>>(), err(ParseErrorKind::InvalidScalar, 17));
+        assert_eq!("Foo(1, 2)".parse::<Complex<i32>>(), err(ParseErrorKind::UnknownName, 0));
+        assert_eq!("Complex(1, 2)".parse::<Dual<i32>>(), err(ParseErrorKind::GammaMismatch, 0));
+        assert_eq!("SplitComplex(1, 2)".parse::<Complex<i32>>(), err(ParseErrorKind::GammaMismatch, 0));
+        assert_eq!("Quaternion(1, 2, 3, 4)".parse::<SplitQuaternion<i32>>(), err(ParseErrorKind::GammaMismatch, 0));
+        assert_eq!("Construct2(1, 2, 3, 4)".parse::<Quaternion<i32>>(), err(ParseErrorKind::GammaMismatch, 0));
+    }
+}
@@ -15,12 +15,13 @@ pub struct NonZero;
 pub struct Unit;
 
 
-impl<T, U> Distribution<Construct<T, U>> for StandardNormal where StandardNormal: Distribution<U> {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Construct<T, U> {
+impl<T, U, S: Gamma> Distribution<Construct<T, U, S>> for StandardNormal where StandardNormal: Distribution<U> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Construct<T, U, S> {
         Construct::new(rng.sample(Self), rng.sample(Self))
     }
 }
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 impl<T: Float, U: NormSqr<Output=T> + Clone> Distribution<Construct<T, U>> for NonZero where StandardNormal: Distribution<Construct<T, U>> {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Construct<T, U> {
         loop {
@@ -0,0 +1,88 @@
+use core::{array, ops::{Neg, Add}};
+use num_traits::{Float, Zero};
+use super::{*, flat::Flat, specific::basis_mul};
+
+
+/// Matrix of the left (`e_i * e_j`) or right (`e_j * e_i`) multiplication by `x` in the standard basis.
+///
+/// `m[k][j]` is the `k`-th component of the product of `x` and `e_j`.
+fn mul_matrix<T, const N: usize>(level: usize, x: &[T], left: bool) -> [[T; N]; N] where T: Zero + Neg<Output=T> + Add<Output=T> + Copy {
+    let mut columns = [[T::zero(); N]; N];
+    for (j, col) in columns.iter_mut().enumerate() {
+        for (i, &c) in x.iter().enumerate() {
+            let (s, k) = if left { basis_mul(level, i, j) } else { basis_mul(level, j, i) };
+            col[k] = if s > 0 { col[k] + c } else { col[k] + -c };
+        }
+    }
+    array::from_fn(|k| array::from_fn(|j| columns[j][k]))
+}
+
+/// Whether all elements of `a` and `b` differ no more than by `epsilon`.
+fn close<T: Float, const N: usize>(a: &[[T; N]; N], b: &[[T; N]; N], epsilon: T) -> bool {
+    a.iter().flatten().zip(b.iter().flatten()).all(|(&x, &y)| (x - y).abs() <= epsilon)
+}
+
+impl<T> Complex<T> where T: Zero + Neg<Output=T> + Add<Output=T> + Copy {
+    /// Real matrix `[[a, -b], [b, a]]` representing `a + bi`.
+    pub fn to_real_matrix(self) -> [[T; 2]; 2] {
+        mul_matrix(Self::LEVEL, self.as_slice(), true)
+    }
+}
+impl<T: Float> Complex<T> {
+    /// Complex number from its real matrix, `None` if the matrix doesn't have the form `[[a, -b], [b, a]]` within `epsilon`.
+    pub fn from_real_matrix(m: [[T; 2]; 2], epsilon: T) -> Option<Self> {
+        let x = Self::new(m[0][0], m[1][0]);
+        if close(&x.to_real_matrix(), &m, epsilon) { Some(x) } else { None }
+    }
+}
+
+macro_rules! impl_mul_matrix { ($U:ident, $N:expr) => (
+    impl<T> $U<T> where T: Zero + Neg<Output=T> + Add<Output=T> + Copy {
+        /// Real matrix `L` of the left multiplication, `self * x == L x` for `x` written as a column of components.
+        pub fn to_left_mul_matrix(self) -> [[T; $N]; $N] {
+            mul_matrix(Self::LEVEL, self.as_slice(), true)
+        }
+        /// Real matrix `R` of the right multiplication, `x * self == R x` for `x` written as a column of components.
+        pub fn to_right_mul_matrix(self) -> [[T; $N]; $N] {
+            mul_matrix(Self::LEVEL, self.as_slice(), false)
+        }
+    }
+    impl<T: Float> $U<T> {
+        /// Number from its left multiplication matrix, `None` if the matrix is not such one within `epsilon`.
+        pub fn from_left_mul_matrix(m: [[T; $N]; $N], epsilon: T) -> Option<Self> {
+            // The first column is the image of the real unit.
+            let x = Self::from_slice(&m.map(|r| r[0]));
+            if close(&x.to_left_mul_matrix(), &m, epsilon) { Some(x) } else { None }
+        }
+        /// Number from its right multiplication matrix, `None` if the matrix is not such one within `epsilon`.
+        pub fn from_right_mul_matrix(m: [[T; $N]; $N], epsilon: T) -> Option<Self> {
+            let x = Self::from_slice(&m.map(|r| r[0]));
+            if close(&x.to_right_mul_matrix(), &m, epsilon) { Some(x) } else { None }
+        }
+    }
+) }
+
+impl_mul_matrix!(Quaternion, 4);
+impl_mul_matrix!(Octonion, 8);
+
+impl<T> Quaternion<T> where T: Conj + Neg<Output=T> + Copy {
+    /// Complex matrix `[[z₁, z₂], [-z̄₂, z̄₁]]` representing `z₁ + z₂j`.
+    ///
+    /// Unit quaternions are mapped onto SU(2).
+    pub fn to_complex_matrix(self) -> [[Complex<T>; 2]; 2] {
+        let (z1, z2) = self.split();
+        [[z1, z2], [-z2.conj(), z1.conj()]]
+    }
+}
+impl<T: Float + Conj> Quaternion<T> {
+    /// Quaternion from its complex matrix, `None` if the matrix doesn't have the form `[[z₁, z₂], [-z̄₂, z̄₁]]` within `epsilon`.
+    pub fn from_complex_matrix(m: [[Complex<T>; 2]; 2], epsilon: T) -> Option<Self> {
+        let x = Self::new(m[0][0], m[0][1]);
+        let e = x.to_complex_matrix();
+        let ok = e.iter().flatten().zip(m.iter().flatten()).all(|(a, b)| {
+            (a.re() - b.re()).abs() <= epsilon && (a.im() - b.im()).abs() <= epsilon
+        });
+        if ok { Some(x) } else { None }
+    }
+}
+
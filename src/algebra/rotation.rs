@@ -0,0 +1,208 @@
+use num_traits::{Float, Inv, One};
+use super::*;
+
+
+/// Sequence of rotation axes for Euler angles.
+///
+/// The first six are Tait–Bryan sequences, the last six are proper Euler sequences.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerSeq {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+/// Whether Euler angle rotations are made around the axes of the rotating body or of the fixed frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerFrame {
+    /// Rotations around the axes of the rotating body, i.e. `q = q1 * q2 * q3`.
+    Intrinsic,
+    /// Rotations around the axes of the fixed frame, i.e. `q = q3 * q2 * q1`.
+    Extrinsic,
+}
+
+impl EulerSeq {
+    /// Indices of the axes, `0` for `X`, `1` for `Y` and `2` for `Z`.
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerSeq::XYZ => [0, 1, 2],
+            EulerSeq::XZY => [0, 2, 1],
+            EulerSeq::YXZ => [1, 0, 2],
+            EulerSeq::YZX => [1, 2, 0],
+            EulerSeq::ZXY => [2, 0, 1],
+            EulerSeq::ZYX => [2, 1, 0],
+            EulerSeq::XYX => [0, 1, 0],
+            EulerSeq::XZX => [0, 2, 0],
+            EulerSeq::YXY => [1, 0, 1],
+            EulerSeq::YZY => [1, 2, 1],
+            EulerSeq::ZXZ => [2, 0, 2],
+            EulerSeq::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+/// Conversions between unit quaternions and other representations of 3D rotations.
+///
+/// Vectors `[x, y, z]` are identified with pure quaternions `xi + yj + zk`,
+/// and quaternion `q` rotates vector `v` as `q * v * q⁻¹`.
+impl<T: Float + Algebra> Quaternion<T> {
+    /// Create from pure quaternion vector part.
+    pub(crate) fn from_vector(v: [T; 3]) -> Self {
+        Self::new2(T::zero(), v[0], v[1], v[2])
+    }
+    /// Get vector part.
+    pub(crate) fn vector(&self) -> [T; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+    /// Rotation around a single coordinate axis.
+    fn from_basis_angle(axis: usize, angle: T) -> Self {
+        let mut v = [T::zero(); 3];
+        v[axis] = T::one();
+        Self::from_axis_angle(v, angle)
+    }
+
+    /// Rotation around the `axis` by the `angle` in radians. The axis doesn't need to be normalized.
+    ///
+    /// Zero axis gives identity rotation.
+    pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+        let v = Self::from_vector(axis);
+        let vn = v.norm();
+        if vn.is_zero() {
+            return Self::one();
+        }
+        let half = angle / (T::one() + T::one());
+        v / vn * half.sin() + half.cos()
+    }
+    /// Get rotation axis and angle in `[0, 2π]`.
+    ///
+    /// The quaternion doesn't need to be normalized. For identity rotation the axis is `[1, 0, 0]`.
+    pub fn to_axis_angle(self) -> ([T; 3], T) {
+        let v = Self::from_vector(self.vector());
+        let vn = v.norm();
+        let angle = (T::one() + T::one()) * vn.atan2(self.w());
+        if vn > T::zero() {
+            ((v / vn).vector(), angle)
+        } else {
+            ([T::one(), T::zero(), T::zero()], angle)
+        }
+    }
+
+    /// Rotate vector by `self`. The quaternion doesn't need to be normalized.
+    pub fn rotate_vector(self, v: [T; 3]) -> [T; 3] {
+        (self * Self::from_vector(v) * self.inv()).vector()
+    }
+
+    /// Get rotation matrix (row-major), i.e. `m * v == q.rotate_vector(v)`.
+    /// The quaternion doesn't need to be normalized.
+    pub fn to_rotation_matrix(self) -> [[T; 3]; 3] {
+        let (w, x, y, z) = (self.w(), self.x(), self.y(), self.z());
+        let s = (T::one() + T::one()) / self.norm_sqr();
+        let (wx, wy, wz) = (s * w * x, s * w * y, s * w * z);
+        let (xx, xy, xz) = (s * x * x, s * x * y, s * x * z);
+        let (yy, yz, zz) = (s * y * y, s * y * z, s * z * z);
+        [
+            [T::one() - (yy + zz), xy - wz, xz + wy],
+            [xy + wz, T::one() - (xx + zz), yz - wx],
+            [xz - wy, yz + wx, T::one() - (xx + yy)],
+        ]
+    }
+    /// Create unit quaternion from rotation matrix (row-major).
+    ///
+    /// Uses Shepperd's method: the largest of the four quaternion components is computed first
+    /// from the diagonal, so there is no loss of precision for any rotation angle.
+    pub fn from_rotation_matrix(m: [[T; 3]; 3]) -> Self {
+        let one = T::one();
+        let half = one / (one + one);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let r = (one + trace).sqrt();
+            let s = half / r;
+            Self::new2(half * r, (m[2][1] - m[1][2]) * s, (m[0][2] - m[2][0]) * s, (m[1][0] - m[0][1]) * s)
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let r = (one + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            let s = half / r;
+            Self::new2((m[2][1] - m[1][2]) * s, half * r, (m[0][1] + m[1][0]) * s, (m[0][2] + m[2][0]) * s)
+        } else if m[1][1] >= m[2][2] {
+            let r = (one - m[0][0] + m[1][1] - m[2][2]).sqrt();
+            let s = half / r;
+            Self::new2((m[0][2] - m[2][0]) * s, (m[0][1] + m[1][0]) * s, half * r, (m[1][2] + m[2][1]) * s)
+        } else {
+            let r = (one - m[0][0] - m[1][1] + m[2][2]).sqrt();
+            let s = half / r;
+            Self::new2((m[1][0] - m[0][1]) * s, (m[0][2] + m[2][0]) * s, (m[1][2] + m[2][1]) * s, half * r)
+        };
+        q.normalize()
+    }
+
+    /// Create from Euler angles in radians, applied in the order of `seq`.
+    pub fn from_euler_angles(seq: EulerSeq, frame: EulerFrame, angles: [T; 3]) -> Self {
+        let axes = seq.axes();
+        let qs = [
+            Self::from_basis_angle(axes[0], angles[0]),
+            Self::from_basis_angle(axes[1], angles[1]),
+            Self::from_basis_angle(axes[2], angles[2]),
+        ];
+        match frame {
+            EulerFrame::Intrinsic => qs[0] * qs[1] * qs[2],
+            EulerFrame::Extrinsic => qs[2] * qs[1] * qs[0],
+        }
+    }
+    /// Get Euler angles in radians for the specified axis sequence.
+    ///
+    /// The first and the last angles lie in `[-π, π]`.
+    /// The second one lies in `[0, π]` for proper Euler sequences and in `[-π/2, π/2]` for Tait–Bryan ones.
+    /// In gimbal lock the first angle of the extrinsic sequence accumulates the whole rotation and the last one is set to zero.
+    ///
+    /// Uses the method from Bernardes and Viollet, "Quaternion to Euler angles conversion:
+    /// A direct, general and computationally efficient method" (2022).
+    pub fn to_euler_angles(self, seq: EulerSeq, frame: EulerFrame) -> [T; 3] {
+        let axes = seq.axes();
+        // Intrinsic rotation is the extrinsic one with reversed order.
+        let (i, j, mut k) = match frame {
+            EulerFrame::Extrinsic => (axes[0], axes[1], axes[2]),
+            EulerFrame::Intrinsic => (axes[2], axes[1], axes[0]),
+        };
+        let proper = i == k;
+        if proper {
+            k = 3 - i - j;
+        }
+        let sign = if (i + 1) % 3 == j { T::one() } else { -T::one() };
+
+        let q = [self.w(), self.x(), self.y(), self.z()];
+        let (a, b, c, d) = if proper {
+            (q[0], q[i + 1], q[j + 1], q[k + 1] * sign)
+        } else {
+            (q[0] - q[j + 1], q[i + 1] + q[k + 1] * sign, q[j + 1] + q[0], q[k + 1] * sign - q[i + 1])
+        };
+
+        let two = T::one() + T::one();
+        let pi = T::from(core::f64::consts::PI).unwrap();
+        let eps = T::epsilon().sqrt();
+        let mut angles = [T::zero(); 3];
+        angles[1] = two * c.hypot(d).atan2(a.hypot(b));
+        let (half_sum, half_diff) = (b.atan2(a), d.atan2(c));
+        if angles[1].abs() <= eps {
+            angles[0] = two * half_sum;
+        } else if (angles[1] - pi).abs() <= eps {
+            angles[0] = -two * half_diff;
+        } else {
+            angles[0] = half_sum - half_diff;
+            angles[2] = half_sum + half_diff;
+        }
+        if !proper {
+            angles[2] = sign * angles[2];
+            angles[1] = angles[1] - pi / two;
+        }
+        for a in angles.iter_mut() {
+            if *a > pi {
+                *a = *a - two * pi;
+            } else if *a < -pi {
+                *a = *a + two * pi;
+            }
+        }
+        match frame {
+            EulerFrame::Extrinsic => angles,
+            EulerFrame::Intrinsic => [angles[2], angles[1], angles[0]],
+        }
+    }
+}
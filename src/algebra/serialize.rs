@@ -0,0 +1,209 @@
+//! Serialization and deserialization using `serde`.
+//!
+//! Hypercomplex numbers are serialized as flat tuples of scalar components,
+//! e.g. `Quaternion<f64>` is `[w, x, y, z]` in JSON.
+//! Quaternions could also be serialized as structs `{w, x, y, z}` using [`quaternion_struct`] module.
+//!
+//! Only the standard Cayley–Dickson construction is supported, `serde` support for split and dual algebras is not provided.
+
+use core::{fmt::{self, Formatter}, marker::PhantomData};
+use serde::{
+    Serialize, Serializer, Deserialize, Deserializer,
+    ser::SerializeTuple,
+    de::{self, Visitor, SeqAccess},
+};
+use super::{construct::*, flat::Flat};
+
+
+/// Hypercomplex number that could be deserialized from flat sequence of scalars, serialization needs only [`Flat`].
+///
+/// Implemented only for the standard Cayley–Dickson construction, split and dual algebras are not serializable.
+pub trait DeserializeFlat<T>: Flat<T> {
+    /// Deserialize components from sequence, `count` is the number of already read components.
+    fn deserialize_flat<'de, A: SeqAccess<'de>>(seq: &mut A, count: &mut usize) -> Result<Self, A::Error> where T: Deserialize<'de>;
+}
+
+impl<T, U> DeserializeFlat<T> for Construct<T, Construct<T, U>> where Construct<T, U>: DeserializeFlat<T> {
+    fn deserialize_flat<'de, A: SeqAccess<'de>>(seq: &mut A, count: &mut usize) -> Result<Self, A::Error> where T: Deserialize<'de> {
+        let re = Construct::deserialize_flat(seq, count)?;
+        let im = Construct::deserialize_flat(seq, count)?;
+        Ok(Self::new(re, im))
+    }
+}
+impl<T> DeserializeFlat<T> for Construct<T, T> {
+    fn deserialize_flat<'de, A: SeqAccess<'de>>(seq: &mut A, count: &mut usize) -> Result<Self, A::Error> where T: Deserialize<'de> {
+        let mut next = || -> Result<T, A::Error> {
+            let x = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(*count, &"all scalar components"))?;
+            *count += 1;
+            Ok(x)
+        };
+        let re = next()?;
+        let im = next()?;
+        Ok(Self::new(re, im))
+    }
+}
+
+impl<T: Serialize, U> Serialize for Construct<T, U> where Self: Flat<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(Self::DIM)?;
+        for x in self.iter() {
+            tuple.serialize_element(x)?;
+        }
+        tuple.end()
+    }
+}
+
+struct FlatVisitor<T, U> {
+    ph: PhantomData<Construct<T, U>>,
+}
+impl<'de, T: Deserialize<'de>, U> Visitor<'de> for FlatVisitor<T, U> where Construct<T, U>: DeserializeFlat<T> {
+    type Value = Construct<T, U>;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} scalars", Construct::<T, U>::DIM)
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut count = 0;
+        Construct::deserialize_flat(&mut seq, &mut count)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, U> Deserialize<'de> for Construct<T, U> where Self: DeserializeFlat<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(Self::DIM, FlatVisitor { ph: PhantomData })
+    }
+}
+
+/// Serialize quaternions as structs `{w, x, y, z}`.
+///
+/// Use it as `#[serde(with = "hcomplex::serialize::quaternion_struct")]`.
+/// Deserialization accepts both struct and sequence forms.
+pub mod quaternion_struct {
+    use core::{fmt::{self, Formatter}, marker::PhantomData};
+    use serde::{
+        Serialize, Serializer, Deserialize, Deserializer,
+        ser::SerializeStruct,
+        de::{self, Visitor, SeqAccess, MapAccess},
+    };
+    use crate::Quaternion;
+
+    const FIELDS: [&str; 4] = ["w", "x", "y", "z"];
+
+    pub fn serialize<T: Serialize, S: Serializer>(q: &Quaternion<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Quaternion", 4)?;
+        s.serialize_field("w", q.w_ref())?;
+        s.serialize_field("x", q.x_ref())?;
+        s.serialize_field("y", q.y_ref())?;
+        s.serialize_field("z", q.z_ref())?;
+        s.end()
+    }
+
+    /// Index of the struct field.
+    struct Field(usize);
+
+    struct FieldVisitor;
+    impl<'de> Visitor<'de> for FieldVisitor {
+        type Value = Field;
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "one of `w`, `x`, `y` or `z`")
+        }
+        fn visit_str<E: de::Error>(self, key: &str) -> Result<Field, E> {
+            FIELDS.iter().position(|f| *f == key).map(Field).ok_or_else(|| de::Error::unknown_field(key, &FIELDS))
+        }
+        fn visit_u64<E: de::Error>(self, index: u64) -> Result<Field, E> {
+            match index {
+                0..=3 => Ok(Field(index as usize)),
+                _ => Err(de::Error::invalid_value(de::Unexpected::Unsigned(index), &self)),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct StructVisitor<T> {
+        ph: PhantomData<T>,
+    }
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for StructVisitor<T> {
+        type Value = Quaternion<T>;
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "a quaternion struct {{w, x, y, z}}")
+        }
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut next = |i| seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self));
+            let (w, x, y, z) = (next(0)?, next(1)?, next(2)?, next(3)?);
+            Ok(Quaternion::new2(w, x, y, z))
+        }
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values: [Option<T>; 4] = [None, None, None, None];
+            while let Some(Field(i)) = map.next_key()? {
+                if values[i].is_some() {
+                    return Err(de::Error::duplicate_field(FIELDS[i]));
+                }
+                values[i] = Some(map.next_value()?);
+            }
+            let [w, x, y, z] = values;
+            let field = |v: Option<T>, i: usize| v.ok_or_else(|| de::Error::missing_field(FIELDS[i]));
+            Ok(Quaternion::new2(field(w, 0)?, field(x, 1)?, field(y, 2)?, field(z, 3)?))
+        }
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<Quaternion<T>, D::Error> {
+        deserializer.deserialize_struct("Quaternion", &FIELDS, StructVisitor { ph: PhantomData })
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::{vec::Vec, string::String};
+    use crate::algebra::*;
+
+    #[test]
+    fn json() {
+        let c = Complex::<f64>::new(1.0, -2.5);
+        assert_eq!(serde_json::to_string(&c).unwrap(), "[1.0,-2.5]");
+        let q = Quaternion::<i32>::new2(1, -2, 3, -4);
+        assert_eq!(serde_json::to_string(&q).unwrap(), "[1,-2,3,-4]");
+        assert_eq!(serde_json::from_str::<Quaternion<i32>>("[1, -2, 3, -4]").unwrap(), q);
+        let o = Octonion::<i32>::new2(Complex::new(1, 2), Complex::new(3, 4), Complex::new(5, 6), Complex::new(7, 8));
+        assert_eq!(serde_json::to_string(&o).unwrap(), "[1,2,3,4,5,6,7,8]");
+        assert_eq!(serde_json::from_str::<Octonion<i32>>("[1,2,3,4,5,6,7,8]").unwrap(), o);
+    }
+
+    #[test]
+    fn json_errors() {
+        assert!(serde_json::from_str::<Quaternion<i32>>("[1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<Quaternion<i32>>("[1, 2, 3, 4, 5]").is_err());
+        assert!(serde_json::from_str::<Complex<i32>>("{\"re\": 1}").is_err());
+    }
+
+    #[test]
+    fn binary() {
+        let s: Sedenion<f32> = "1.5 + 2e3 - 4e15".parse().unwrap();
+        let bytes = bincode::serialize(&s).unwrap();
+        assert_eq!(bytes.len(), 16 * 4);
+        assert_eq!(bincode::deserialize::<Sedenion<f32>>(&bytes).unwrap(), s);
+    }
+
+    #[test]
+    fn quaternion_struct() {
+        use crate::serialize::quaternion_struct::{serialize, deserialize};
+        let q = Quaternion::new2(1.0, 0.0, -0.5, 2.0);
+        let mut json = Vec::new();
+        serialize(&q, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), "{\"w\":1.0,\"x\":0.0,\"y\":-0.5,\"z\":2.0}");
+        let from_json = |s: &str| deserialize::<f64, _>(&mut serde_json::Deserializer::from_str(s));
+        assert_eq!(from_json("{\"z\":2,\"y\":-0.5,\"x\":0,\"w\":1}").unwrap(), q);
+        assert_eq!(from_json("[1, 0, -0.5, 2]").unwrap(), q);
+        assert!(from_json("{\"w\":1,\"x\":0,\"y\":-0.5}").is_err());
+        assert!(from_json("{\"w\":1,\"x\":0,\"y\":-0.5,\"z\":2,\"x\":0}").is_err());
+        assert!(from_json("{\"w\":1,\"x\":0,\"y\":-0.5,\"t\":2}").is_err());
+
+        let mut bytes = Vec::new();
+        serialize(&q, &mut bincode::Serializer::new(&mut bytes, bincode::DefaultOptions::new())).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+        assert_eq!(deserialize::<f64, _>(&mut de).unwrap(), q);
+    }
+}
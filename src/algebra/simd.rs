@@ -0,0 +1,179 @@
+//! SIMD-accelerated multiplication of `Quaternion<f32>`, `Quaternion<f64>` and `Complex<f32>`.
+//!
+//! Stable Rust has no specialization, so the `*` operator always uses the generic Cayley–Dickson product.
+//! Fast paths are provided by the [`SimdMul`] trait instead.
+//!
+//! The fast paths perform exactly the same floating-point operations in the same order as the generic product,
+//! so the results are bit-identical to it (except for the sign and payload of NaNs).
+//!
+//! On `x86_64` the quaternion paths use SSE2 which is always available.
+//! The `f64` quaternion path uses a single AVX2 register instead of two SSE2 ones
+//! if AVX2 is enabled at compile time, e.g. with `-C target-feature=+avx2` or `-C target-cpu=native`.
+//! `Complex<f32>` has only two lanes, which is too few for SSE to beat the scalar product, so it uses the scalar code.
+//! On other architectures the portable scalar code is used.
+//!
+//! A single product gains most when products depend on each other, e.g. when rotations are accumulated.
+//! Independent products in a loop are often vectorized across elements by the compiler,
+//! so compare with the generic product using `cargo bench --features simd` before switching.
+
+use super::*;
+
+
+/// Multiplication using SIMD instructions where available.
+pub trait SimdMul {
+    /// Compute `self * other`.
+    fn mul_simd(self, other: Self) -> Self;
+}
+
+/// Scalar product of quaternions.
+///
+/// Each component is `(l1 ± l2) ± (l3 ± l4)`, the same way as the generic product computes it.
+macro_rules! quaternion_scalar { ($T:ident, $name:ident) => (
+    #[inline]
+    pub fn $name(a: &Quaternion<$T>, b: &Quaternion<$T>) -> Quaternion<$T> {
+        let (w1, x1, y1, z1) = (a.w(), a.x(), a.y(), a.z());
+        let (w2, x2, y2, z2) = (b.w(), b.x(), b.y(), b.z());
+        Quaternion::new2(
+            (w1 * w2 - x1 * x2) - (y1 * y2 + z1 * z2),
+            (w1 * x2 + x1 * w2) - (z1 * y2 - y1 * z2),
+            (w1 * y2 - x1 * z2) + (y1 * w2 + z1 * x2),
+            (x1 * y2 + w1 * z2) + (z1 * w2 - y1 * x2),
+        )
+    }
+) }
+
+/// Portable scalar code used where SIMD is unavailable.
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+pub(crate) mod scalar {
+    use super::*;
+
+    quaternion_scalar!(f32, quaternion_f32);
+    quaternion_scalar!(f64, quaternion_f64);
+
+    #[inline]
+    pub fn complex_f32(a: &Complex<f32>, b: &Complex<f32>) -> Complex<f32> {
+        let (p0, p1) = (a.re(), a.im());
+        let (q0, q1) = (b.re(), b.im());
+        Complex::new(p0 * q0 + -(p1 * q1), p0 * q1 + p1 * q0)
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+use scalar::*;
+
+#[cfg(target_arch = "x86_64")]
+use x86_64::*;
+// Two lanes are too few for SSE to pay off, the scalar product is faster.
+#[cfg(target_arch = "x86_64")]
+use scalar::complex_f32;
+
+/// Operands are loaded from and results are stored to the `#[repr(C)]` layout of `Construct` directly.
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use core::{arch::x86_64::*, mem::MaybeUninit};
+    use super::*;
+
+    /// Permute lanes of `v`, `r[n] = v[in]`.
+    macro_rules! permute { ($f:ident, $v:expr, $i0:literal, $i1:literal, $i2:literal, $i3:literal) => (
+        $f::<{ $i0 | ($i1 << 2) | ($i2 << 4) | ($i3 << 6) }>($v, $v)
+    ) }
+    #[cfg(target_feature = "avx2")]
+    macro_rules! permute_pd { ($v:expr, $i0:literal, $i1:literal, $i2:literal, $i3:literal) => (
+        _mm256_permute4x64_pd::<{ $i0 | ($i1 << 2) | ($i2 << 4) | ($i3 << 6) }>($v)
+    ) }
+
+    #[inline]
+    pub fn quaternion_f32(a: &Quaternion<f32>, b: &Quaternion<f32>) -> Quaternion<f32> {
+        let mut r = MaybeUninit::<Quaternion<f32>>::uninit();
+        // SSE2 is a part of the `x86_64` baseline.
+        unsafe {
+            let a = _mm_loadu_ps(a as *const _ as *const f32);
+            let b = _mm_loadu_ps(b as *const _ as *const f32);
+            let sign = |w, x, y, z| _mm_set_ps(z, y, x, w);
+            let l1 = _mm_mul_ps(permute!(_mm_shuffle_ps, a, 0, 0, 0, 1), permute!(_mm_shuffle_ps, b, 0, 1, 2, 2));
+            let l2 = _mm_mul_ps(permute!(_mm_shuffle_ps, a, 1, 1, 1, 0), permute!(_mm_shuffle_ps, b, 1, 0, 3, 3));
+            let l3 = _mm_mul_ps(permute!(_mm_shuffle_ps, a, 2, 3, 2, 3), permute!(_mm_shuffle_ps, b, 2, 2, 0, 0));
+            let l4 = _mm_mul_ps(permute!(_mm_shuffle_ps, a, 3, 2, 3, 2), permute!(_mm_shuffle_ps, b, 3, 3, 1, 1));
+            // `x - y` is exactly `x + (-y)`, and negation only flips the sign bit.
+            let l2 = _mm_xor_ps(l2, sign(-0.0, 0.0, -0.0, 0.0));
+            let l4 = _mm_xor_ps(l4, sign(0.0, -0.0, 0.0, -0.0));
+            let s = _mm_xor_ps(_mm_add_ps(l3, l4), sign(-0.0, -0.0, 0.0, 0.0));
+            _mm_storeu_ps(r.as_mut_ptr() as *mut f32, _mm_add_ps(_mm_add_ps(l1, l2), s));
+            r.assume_init()
+        }
+    }
+
+    #[cfg(target_feature = "avx2")]
+    #[inline]
+    pub fn quaternion_f64(a: &Quaternion<f64>, b: &Quaternion<f64>) -> Quaternion<f64> {
+        let mut r = MaybeUninit::<Quaternion<f64>>::uninit();
+        // AVX2 is enabled at compile time.
+        unsafe {
+            let a = _mm256_loadu_pd(a as *const _ as *const f64);
+            let b = _mm256_loadu_pd(b as *const _ as *const f64);
+            let sign = |w, x, y, z| _mm256_set_pd(z, y, x, w);
+            let l1 = _mm256_mul_pd(permute_pd!(a, 0, 0, 0, 1), permute_pd!(b, 0, 1, 2, 2));
+            let l2 = _mm256_mul_pd(permute_pd!(a, 1, 1, 1, 0), permute_pd!(b, 1, 0, 3, 3));
+            let l3 = _mm256_mul_pd(permute_pd!(a, 2, 3, 2, 3), permute_pd!(b, 2, 2, 0, 0));
+            let l4 = _mm256_mul_pd(permute_pd!(a, 3, 2, 3, 2), permute_pd!(b, 3, 3, 1, 1));
+            let l2 = _mm256_xor_pd(l2, sign(-0.0, 0.0, -0.0, 0.0));
+            let l4 = _mm256_xor_pd(l4, sign(0.0, -0.0, 0.0, -0.0));
+            let s = _mm256_xor_pd(_mm256_add_pd(l3, l4), sign(-0.0, -0.0, 0.0, 0.0));
+            _mm256_storeu_pd(r.as_mut_ptr() as *mut f64, _mm256_add_pd(_mm256_add_pd(l1, l2), s));
+            r.assume_init()
+        }
+    }
+
+    #[cfg(not(target_feature = "avx2"))]
+    #[inline]
+    pub fn quaternion_f64(a: &Quaternion<f64>, b: &Quaternion<f64>) -> Quaternion<f64> {
+        let mut r = MaybeUninit::<Quaternion<f64>>::uninit();
+        // SSE2 is a part of the `x86_64` baseline, the lanes `[w, x]` and `[y, z]` are computed in separate registers
+        // with the same operands and signs as in the `f32` path.
+        unsafe {
+            let (pa, pb, pr) = (a as *const _ as *const f64, b as *const _ as *const f64, r.as_mut_ptr() as *mut f64);
+            let (a01, a23) = (_mm_loadu_pd(pa), _mm_loadu_pd(pa.add(2)));
+            let (b01, b23) = (_mm_loadu_pd(pb), _mm_loadu_pd(pb.add(2)));
+            let (a00, a11, a10, a32) = (
+                _mm_unpacklo_pd(a01, a01), _mm_unpackhi_pd(a01, a01),
+                _mm_shuffle_pd::<1>(a01, a01), _mm_shuffle_pd::<1>(a23, a23),
+            );
+            let (b00, b11, b10) = (_mm_unpacklo_pd(b01, b01), _mm_unpackhi_pd(b01, b01), _mm_shuffle_pd::<1>(b01, b01));
+            let (b22, b33) = (_mm_unpacklo_pd(b23, b23), _mm_unpackhi_pd(b23, b23));
+            let sign = |x, y| _mm_setr_pd(x, y);
+
+            let l1 = _mm_mul_pd(a00, b01);
+            let l2 = _mm_xor_pd(_mm_mul_pd(a11, b10), sign(-0.0, 0.0));
+            let l3 = _mm_mul_pd(a23, b22);
+            let l4 = _mm_xor_pd(_mm_mul_pd(a32, b33), sign(0.0, -0.0));
+            let s = _mm_xor_pd(_mm_add_pd(l3, l4), sign(-0.0, -0.0));
+            _mm_storeu_pd(pr, _mm_add_pd(_mm_add_pd(l1, l2), s));
+
+            let l1 = _mm_mul_pd(a01, b22);
+            let l2 = _mm_xor_pd(_mm_mul_pd(a10, b33), sign(-0.0, 0.0));
+            let l3 = _mm_mul_pd(a23, b00);
+            let l4 = _mm_xor_pd(_mm_mul_pd(a32, b11), sign(0.0, -0.0));
+            _mm_storeu_pd(pr.add(2), _mm_add_pd(_mm_add_pd(l1, l2), _mm_add_pd(l3, l4)));
+            r.assume_init()
+        }
+    }
+}
+
+impl SimdMul for Quaternion<f32> {
+    #[inline]
+    fn mul_simd(self, other: Self) -> Self {
+        quaternion_f32(&self, &other)
+    }
+}
+impl SimdMul for Quaternion<f64> {
+    #[inline]
+    fn mul_simd(self, other: Self) -> Self {
+        quaternion_f64(&self, &other)
+    }
+}
+impl SimdMul for Complex<f32> {
+    #[inline]
+    fn mul_simd(self, other: Self) -> Self {
+        complex_f32(&self, &other)
+    }
+}
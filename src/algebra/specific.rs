@@ -1,5 +1,5 @@
 use num_traits::{One, Zero};
-use super::*;
+use super::{*, flat::Flat};
 
 
 impl<T: One + Zero> Complex<T> {
@@ -19,3 +19,114 @@ impl<T: One + Zero> Quaternion<T> {
         Self::new2(T::zero(), T::zero(), T::zero(), T::one())
     }
 }
+
+/// Product of basis elements `e_i * e_j` at the specified level as `(sign, index)`.
+pub(crate) const fn basis_mul(level: usize, i: usize, j: usize) -> (i8, usize) {
+    if level == 0 {
+        return (1, 0);
+    }
+    let half = 1 << (level - 1);
+    // Sign of conjugation of `q`, the lower level part of `e_j`.
+    let conj = if j == 0 || j == half { 1 } else { -1 };
+    match (i < half, j < half) {
+        // `(p, 0) * (q, 0) = (pq, 0)`
+        (true, true) => basis_mul(level - 1, i, j),
+        // `(p, 0) * (0, q) = (0, qp)`
+        (true, false) => {
+            let (s, k) = basis_mul(level - 1, j - half, i);
+            (s, k + half)
+        }
+        // `(0, p) * (q, 0) = (0, p q*)`
+        (false, true) => {
+            let (s, k) = basis_mul(level - 1, i - half, j);
+            (conj * s, k + half)
+        }
+        // `(0, p) * (0, q) = (-q* p, 0)`
+        (false, false) => {
+            let (s, k) = basis_mul(level - 1, j - half, i - half);
+            (-conj * s, k)
+        }
+    }
+}
+
+impl<T, U> Construct<T, U> where Self: Flat<T> {
+    /// Multiplication table of basis elements of the standard Cayley–Dickson algebra: `e_i * e_j = sign * e_index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is not less than the dimension.
+    pub const fn basis_mul(i: usize, j: usize) -> (i8, usize) {
+        assert!(i < Self::DIM && j < Self::DIM, "basis element index out of range");
+        basis_mul(Self::LEVEL, i, j)
+    }
+}
+
+impl<T: One + Zero, U, S: Gamma> Construct<T, U, S> where Self: Flat<T> + Zero {
+    /// Basis element `e_n`, where `e_0` is the real unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not less than the dimension.
+    pub fn basis(n: usize) -> Self {
+        let mut x = Self::zero();
+        x[n] = T::one();
+        x
+    }
+    /// Shorthand for [`basis`](Self::basis).
+    pub fn e(n: usize) -> Self {
+        Self::basis(n)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num_traits::{One, Zero};
+    use crate::{*, flat::Flat};
+
+    fn check_table<C>() where C: Flat<i32> + Zero + One + Copy + PartialEq + core::fmt::Debug + core::ops::Mul<Output=C> + core::ops::Neg<Output=C> + core::ops::Index<usize, Output=i32> + core::ops::IndexMut<usize> {
+        let e = |n: usize| {
+            let mut x = C::zero();
+            x[n] = 1;
+            x
+        };
+        for i in 0..C::DIM {
+            for j in 0..C::DIM {
+                let (sign, k) = super::basis_mul(C::LEVEL, i, j);
+                let p = e(i) * e(j);
+                assert_eq!(p, if sign > 0 { e(k) } else { -e(k) });
+            }
+        }
+        assert_eq!(e(0), C::one());
+    }
+
+    #[test]
+    fn table() {
+        check_table::<Complex<i32>>();
+        check_table::<Quaternion<i32>>();
+        check_table::<Octonion<i32>>();
+        check_table::<Sedenion<i32>>();
+    }
+
+    #[test]
+    fn basis() {
+        assert_eq!(Complex::<i32>::e(1), Complex::i());
+        assert_eq!(Quaternion::<i32>::e(2), Quaternion::j());
+        assert_eq!(Quaternion::<i32>::basis(3), Quaternion::k());
+        assert_eq!(Octonion::<i32>::e(7).as_slice(), &[0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(Sedenion::<i32>::e(0), Sedenion::one());
+        assert_eq!((Quaternion::<i32>::LEVEL, Quaternion::<i32>::DIM), (2, 4));
+        assert_eq!((Sedenion::<f64>::LEVEL, Sedenion::<f64>::DIM), (4, 16));
+
+        const IJ: (i8, usize) = Quaternion::<f64>::basis_mul(1, 2);
+        assert_eq!(IJ, (1, 3));
+        assert_eq!(Quaternion::<f64>::basis_mul(2, 1), (-1, 3));
+        assert_eq!(Octonion::<f64>::basis_mul(5, 5), (-1, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn basis_out_of_range() {
+        Octonion::<i32>::e(8);
+    }
+}
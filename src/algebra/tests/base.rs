@@ -182,3 +182,69 @@ fn one4() {
     let a = Quaternion::<f32>::one();
     assert_abs_diff_eq!(a, Quaternion::new2(1.0, 0.0, 0.0, 0.0));
 }
+
+#[test]
+fn markers() {
+    fn commutative<A: Commutative>() {}
+    fn associative<A: Associative>() {}
+    fn alternative<A: Alternative>() {}
+    fn division<A: DivisionAlgebra>() {}
+
+    commutative::<Complex<f64>>();
+    commutative::<Dual<f64>>();
+    associative::<Quaternion<f64>>();
+    associative::<SplitQuaternion<f64>>();
+    alternative::<Octonion<f64>>();
+    alternative::<SplitOctonion<f64>>();
+    division::<Complex<f64>>();
+    division::<Quaternion<f64>>();
+    division::<Octonion<f64>>();
+}
+
+#[test]
+fn reverse_int() {
+    let c = Complex::<i32>::new(1, 2);
+    assert_eq!(2 * c, Complex::new(2, 4));
+    assert_eq!(1 + c, Complex::new(2, 2));
+    assert_eq!(1 - c, Complex::new(0, -2));
+    assert_eq!(3i8 * Quaternion::new2(1, 2, 3, 4), Quaternion::new2(3, 6, 9, 12));
+}
+
+mod custom {
+    use core::ops::{Neg, Add, Sub, Mul, Div};
+    use crate::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Scalar(pub i64);
+
+    impl Neg for Scalar {
+        type Output = Self;
+        fn neg(self) -> Self { Scalar(-self.0) }
+    }
+    impl Add for Scalar {
+        type Output = Self;
+        fn add(self, other: Self) -> Self { Scalar(self.0 + other.0) }
+    }
+    impl Sub for Scalar {
+        type Output = Self;
+        fn sub(self, other: Self) -> Self { Scalar(self.0 - other.0) }
+    }
+    impl Mul for Scalar {
+        type Output = Self;
+        fn mul(self, other: Self) -> Self { Scalar(self.0 * other.0) }
+    }
+    impl Div for Scalar {
+        type Output = Self;
+        fn div(self, other: Self) -> Self { Scalar(self.0 / other.0) }
+    }
+
+    crate::impl_reverse_ops!(Scalar);
+
+    #[test]
+    fn reverse() {
+        let q = Quaternion::new2(Scalar(1), Scalar(2), Scalar(3), Scalar(4));
+        assert_eq!(Scalar(2) * q, q * Scalar(2));
+        assert_eq!(Scalar(1) + q, Quaternion::new2(Scalar(2), Scalar(2), Scalar(3), Scalar(4)));
+        assert_eq!(Scalar(1) - q, Quaternion::new2(Scalar(0), Scalar(-2), Scalar(-3), Scalar(-4)));
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::{vec, vec::Vec};
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{*, random::*, batch::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+
+
+fn sample_vec<R: Rng, T, U>(rng: &mut R) -> Vec<Construct<T, U>> where NonZero: Distribution<Construct<T, U>> {
+    (0..SAMPLE_ATTEMPTS).map(|_| rng.sample(NonZero)).collect()
+}
+
+#[test]
+fn conversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBA7C0);
+    let v: Vec<Quaternion<f32>> = sample_vec(&mut rng);
+    let soa = QuaternionSoA::from(v.clone());
+    assert_eq!(soa.len(), v.len());
+    assert_eq!(soa.component(2), v.iter().map(|q| q.y()).collect::<Vec<_>>().as_slice());
+    assert_eq!(soa.get(3), Some(v[3]));
+    assert_eq!(soa.get::<Complex<f32>>(v.len()), None);
+    assert_eq!(Vec::<Quaternion<f32>>::from(soa), v);
+
+    let mut soa = ComplexSoA::new();
+    soa.push(Complex::new(1, 2));
+    soa.push(Complex::new(3, 4));
+    assert_eq!(soa.into_components(), [vec![1, 3], vec![2, 4]]);
+}
+
+#[test]
+#[should_panic]
+fn dimension_mismatch() {
+    let mut soa = OctonionSoA::new();
+    soa.push(Quaternion::new2(1, 2, 3, 4));
+}
+
+#[test]
+fn arithmetic() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBA7C1);
+    let (a, b): (Vec<Octonion<f64>>, Vec<Octonion<f64>>) = (sample_vec(&mut rng), sample_vec(&mut rng));
+    let (sa, sb) = (OctonionSoA::from(a.clone()), OctonionSoA::from(b.clone()));
+    let (mul, div, conj, norm, normalize) = (sa.mul(&sb), sa.div(&sb), sa.conj(), sa.norm(), sa.normalize());
+    let c: Octonion<f64> = rng.sample(NonZero);
+    let (pre, post) = (sa.premul(&c), sa.postmul(&c));
+    let at = |s: &OctonionSoA<f64>, n| s.get::<Octonion<f64>>(n).unwrap();
+    for (n, (&x, &y)) in a.iter().zip(b.iter()).enumerate() {
+        assert_abs_diff_eq!(at(&mul, n), x * y, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&div, n), x / y, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&conj, n), x.conj(), epsilon=1e-12);
+        assert_abs_diff_eq!(norm[n], x.norm(), epsilon=1e-12);
+        assert_abs_diff_eq!(at(&normalize, n), x.normalize(), epsilon=1e-12);
+        assert_abs_diff_eq!(at(&pre, n), c * x, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&post, n), x * c, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn integer() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBA7C2);
+    let d = rand_distr::Uniform::from(-100..100);
+    let a: Vec<Sedenion<i64>> = (0..SAMPLE_ATTEMPTS).map(|_| Sedenion::from_slice(&[(); 16].map(|_| rng.sample(d)))).collect();
+    let b: Vec<Sedenion<i64>> = a.iter().rev().cloned().collect();
+    let prod = SedenionSoA::from(a.clone()).mul(&SedenionSoA::from(b.clone()));
+    let expected: Vec<Sedenion<i64>> = a.iter().zip(b.iter()).map(|(&x, &y)| x * y).collect();
+    assert_eq!(prod.to_vec::<Sedenion<i64>>(), expected);
+}
+
+#[test]
+fn lower_level() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBA7C3);
+    let a: Vec<Quaternion<f64>> = sample_vec(&mut rng);
+    let c: Complex<f64> = rng.sample(NonZero);
+    let soa = QuaternionSoA::from(a.clone());
+    let (pre, post, sum) = (soa.premul(&c), soa.postmul(&c), soa.add_const(&c));
+    let at = |s: &QuaternionSoA<f64>, n| s.get::<Quaternion<f64>>(n).unwrap();
+    for (n, &x) in a.iter().enumerate() {
+        assert_abs_diff_eq!(at(&pre, n), c * x, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&post, n), x * c, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&sum, n), x + c, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn lower_level_noncommutative() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBA7C4);
+    let a: Vec<Sedenion<f64>> = sample_vec(&mut rng);
+    let c: Quaternion<f64> = rng.sample(NonZero);
+    let soa = SedenionSoA::from(a.clone());
+    let (pre, post) = (soa.premul(&c), soa.postmul(&c));
+    let at = |s: &SedenionSoA<f64>, n| s.get::<Sedenion<f64>>(n).unwrap();
+    for (n, &x) in a.iter().enumerate() {
+        assert_abs_diff_eq!(at(&pre, n), c * x, epsilon=1e-12);
+        assert_abs_diff_eq!(at(&post, n), x * c, epsilon=1e-12);
+    }
+}
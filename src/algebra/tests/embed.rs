@@ -0,0 +1,71 @@
+use core::{convert::TryFrom, ops::IndexMut};
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{prelude::*, *, random::*, embed::ProjectError};
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+
+fn embed<T: Zero + Copy, L: Flat<T>, H: Flat<T> + Zero + IndexMut<usize, Output=T>>(x: &L) -> H {
+    let mut y = H::zero();
+    for (i, &c) in crate::flat::as_slice(x).iter().enumerate() {
+        y[i] = c;
+    }
+    y
+}
+
+#[test]
+fn embedding() {
+    let c = Complex::new(1, 2);
+    assert_eq!(Quaternion::from(c), Quaternion::new2(1, 2, 0, 0));
+    assert_eq!(Octonion::from(c).as_slice(), &[1, 2, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Sedenion::from(c).as_slice()[..4], [1, 2, 0, 0]);
+    assert_eq!(Sedenion::from(Quaternion::new2(1, 2, 3, 4)).as_slice()[..6], [1, 2, 3, 4, 0, 0]);
+    assert_eq!(Sedenion::from(Octonion::from(c)), Sedenion::from(c));
+}
+
+#[test]
+fn projection() {
+    let c = Complex::new(1, 2);
+    assert_eq!(Sedenion::from(c).project::<Complex<i32>>(), Ok(c));
+    assert_eq!(Complex::try_from(Octonion::from(c)), Ok(c));
+    assert_eq!(Octonion::from(Quaternion::new2(1, 2, 3, 4)).project::<Quaternion<i32>>(), Ok(Quaternion::new2(1, 2, 3, 4)));
+
+    let mut s = Sedenion::from(c);
+    s[5] = 1;
+    s[11] = -1;
+    let e: ProjectError = s.project::<Complex<i32>>().unwrap_err();
+    assert_eq!((e.index(), e.count()), (5, 2));
+    assert_eq!(s.project::<Octonion<i32>>().unwrap_err().index(), 11);
+}
+
+#[test]
+fn mixed_arithmetic() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE3BE0);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let c: Complex<f64> = rng.sample(NonZero);
+        let q: Quaternion<f64> = rng.sample(NonZero);
+        let o: Octonion<f64> = rng.sample(NonZero);
+        let s: Sedenion<f64> = rng.sample(NonZero);
+        let (cs, qs, qo): (Sedenion<f64>, Sedenion<f64>, Octonion<f64>) = (embed(&c), embed(&q), embed(&q));
+
+        assert_abs_diff_eq!(c * s, cs * s, epsilon=1e-12);
+        assert_abs_diff_eq!(s * c, s * cs, epsilon=1e-12);
+        assert_abs_diff_eq!(q * s, qs * s, epsilon=1e-12);
+        assert_abs_diff_eq!(s * q, s * qs, epsilon=1e-12);
+        assert_abs_diff_eq!(q * o, qo * o, epsilon=1e-12);
+        assert_abs_diff_eq!(o * q, o * qo, epsilon=1e-12);
+
+        assert_abs_diff_eq!(s / c, s / cs, epsilon=1e-10);
+        assert_abs_diff_eq!(q / o, qo / o, epsilon=1e-10);
+        assert_abs_diff_eq!(c + s, cs + s, epsilon=1e-12);
+        assert_abs_diff_eq!(c - s, cs - s, epsilon=1e-12);
+        assert_abs_diff_eq!(s - q, s - qs, epsilon=1e-12);
+
+        let mut t = s;
+        t *= q;
+        t += c;
+        assert_abs_diff_eq!(t, s * qs + cs, epsilon=1e-12);
+    }
+}
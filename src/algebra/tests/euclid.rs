@@ -0,0 +1,132 @@
+use rand::{prelude::*};
+use rand_distr::{Uniform};
+use rand_xorshift::XorShiftRng;
+use crate::{prelude::*, *};
+
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Ci = Complex<i64>;
+type Qi = Quaternion<i64>;
+
+
+fn sample_complex<R: Rng>(rng: &mut R) -> Ci {
+    let d = Uniform::from(-1000..1000);
+    Ci::new(rng.sample(d), rng.sample(d))
+}
+fn sample_quaternion<R: Rng>(rng: &mut R) -> Qi {
+    let d = Uniform::from(-1000..1000);
+    Qi::new2(rng.sample(d), rng.sample(d), rng.sample(d), rng.sample(d))
+}
+
+#[test]
+fn round_div() {
+    assert_eq!(7.round_div(2), 4);
+    assert_eq!(5.round_div(3), 2);
+    assert_eq!(4.round_div(3), 1);
+    assert_eq!((-7).round_div(2), -4);
+    assert_eq!((-5).round_div(3), -2);
+    assert_eq!(7.round_div(-2), -4);
+    assert_eq!(2.5f64.round_div(-1.0), -3.0);
+}
+
+#[test]
+fn gaussian() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE0C0);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = sample_complex(&mut rng);
+        let b = sample_complex(&mut rng);
+        if b.is_zero() {
+            continue;
+        }
+        let (q, r) = a.div_rem(b);
+        assert_eq!(q * b + r, a);
+        assert!(2 * r.norm_sqr() <= b.norm_sqr());
+        assert_eq!(a % b, r);
+        assert_eq!(a.div_euclid(b), q);
+    }
+}
+
+#[test]
+fn gaussian_gcd() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE0C1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = sample_complex(&mut rng);
+        let b = sample_complex(&mut rng);
+        let c = sample_complex(&mut rng);
+        if c.is_zero() {
+            continue;
+        }
+        let g = (a * c).gcd(b * c);
+        assert!(g.re() > 0 && g.im() >= 0);
+        assert!((g % c).is_zero());
+        assert!(((a * c) % g).is_zero());
+        assert!(((b * c) % g).is_zero());
+    }
+    assert_eq!(Ci::new(0, -3).gcd(Ci::zero()), Ci::new(3, 0));
+    assert_eq!(Ci::zero().gcd(Ci::zero()), Ci::zero());
+}
+
+#[test]
+fn lipschitz() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE0C2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = sample_quaternion(&mut rng);
+        let b = sample_quaternion(&mut rng);
+        if b.is_zero() {
+            continue;
+        }
+        let (q, r) = a.div_rem(b);
+        assert_eq!(q * b + r, a);
+        assert!(r.norm_sqr() <= b.norm_sqr());
+        let (q, r) = a.div_rem_left(b);
+        assert_eq!(b * q + r, a);
+        assert!(r.norm_sqr() <= b.norm_sqr());
+    }
+}
+
+#[test]
+fn hurwitz() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE0C3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = Hurwitz::from_double(sample_quaternion(&mut rng) * 2 + Qi::new2(1, 1, 1, 1) * rng.sample(Uniform::from(0..2))).unwrap();
+        let b = Hurwitz::from(sample_quaternion(&mut rng));
+        if b.is_zero() {
+            continue;
+        }
+        let (q, r) = a.div_rem(b);
+        assert_eq!(q * b + r, a);
+        assert!(2 * r.norm_sqr() <= b.norm_sqr());
+        let (q, r) = a.div_rem_left(b);
+        assert_eq!(b * q + r, a);
+        assert!(2 * r.norm_sqr() <= b.norm_sqr());
+    }
+}
+
+#[test]
+fn hurwitz_gcd() {
+    let mut rng = XorShiftRng::seed_from_u64(0xE0C4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = Hurwitz::from(sample_quaternion(&mut rng));
+        let b = Hurwitz::from(sample_quaternion(&mut rng));
+        let c = Hurwitz::from(sample_quaternion(&mut rng));
+        let g = (a * c).gcd(b * c);
+        if g.is_zero() {
+            continue;
+        }
+        assert!(((a * c) % g).is_zero());
+        assert!(((b * c) % g).is_zero());
+        assert!((g % c).is_zero());
+    }
+}
+
+#[test]
+fn hurwitz_conversion() {
+    let q = Qi::new2(1, -2, 3, -4);
+    assert_eq!(Hurwitz::from(q).into_lipschitz(), Some(q));
+    assert_eq!(Hurwitz::from(q).into_double(), q * 2);
+    assert_eq!(Hurwitz::from_double(Qi::new2(1, 1, -1, 3)).unwrap().into_lipschitz(), None);
+    assert_eq!(Hurwitz::from_double(Qi::new2(1, 2, -1, 3)), None);
+    let h = Hurwitz::from_double(Qi::new2(1, 1, 1, 1)).unwrap();
+    assert_eq!(h.norm_sqr(), 1);
+    assert_eq!((h * h * h).into_lipschitz(), Some(-Qi::one()));
+}
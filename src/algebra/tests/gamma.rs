@@ -0,0 +1,94 @@
+use crate::*;
+use num_traits::{Zero, One, Inv};
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use crate::random::*;
+use ::approx::*;
+
+
+const SAMPLE_ATTEMPTS: usize = 256;
+
+#[test]
+fn dual_square() {
+    let e = Dual::<i32>::new(0, 1);
+    assert_eq!(e * e, Dual::zero());
+}
+
+#[test]
+fn dual_derivative() {
+    // p(x) = 3x³ - 2x + 5, p'(x) = 9x² - 2
+    let p = |x: Dual<f64>| x * x * x * 3.0 - x * 2.0 + Dual::one() * 5.0;
+    let x = 1.5;
+    let y = p(Dual::new(x, 1.0));
+    assert_abs_diff_eq!(y.re(), 3.0 * x * x * x - 2.0 * x + 5.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(y.im(), 9.0 * x * x - 2.0, epsilon = 1e-12);
+}
+
+#[test]
+fn split_complex_square() {
+    let j = SplitComplex::<i32>::new(0, 1);
+    assert_eq!(j * j, SplitComplex::one());
+}
+
+#[test]
+fn split_complex_norm() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: SplitComplex<f64> = rng.sample(StandardNormal);
+        let b: SplitComplex<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(a.norm_sqr(), a.re() * a.re() - a.im() * a.im(), epsilon = 1e-12);
+        assert_abs_diff_eq!((a * b).norm_sqr(), a.norm_sqr() * b.norm_sqr(), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn split_quaternion_norm() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        let b: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b).norm_sqr(), a.norm_sqr() * b.norm_sqr(), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn split_quaternion_associativity() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        let b: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        let c: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b) * c, a * (b * c), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn split_quaternion_inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: SplitQuaternion<f64> = rng.sample(StandardNormal);
+        if a.norm_sqr().abs() < 1e-2 {
+            continue;
+        }
+        assert_abs_diff_eq!(a * a.inv(), SplitQuaternion::one(), epsilon = 1e-8);
+        assert_abs_diff_eq!(a.inv() * a, SplitQuaternion::one(), epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn split_quaternion_zero_divisor() {
+    let a = SplitQuaternion::<i32>::new2(1, 0, 1, 0);
+    let b = SplitQuaternion::<i32>::new2(1, 0, -1, 0);
+    assert_eq!(a.norm_sqr(), 0);
+    assert_eq!(a * b, SplitQuaternion::zero());
+}
+
+#[test]
+fn split_octonion_norm() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: SplitOctonion<f64> = rng.sample(StandardNormal);
+        let b: SplitOctonion<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b).norm_sqr(), a.norm_sqr() * b.norm_sqr(), epsilon = 1e-9);
+    }
+}
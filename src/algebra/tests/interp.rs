@@ -0,0 +1,145 @@
+use rand::{prelude::*};
+use rand_distr::{Uniform};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{prelude::*, *, random::*, interp::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Qf = Quaternion<f64>;
+
+
+/// Check that both quaternions represent the same rotation.
+fn assert_same_rotation(a: Qf, b: Qf, eps: f64) {
+    let b = if a.dot(b) < 0.0 { -b } else { b };
+    assert_abs_diff_eq!(a, b, epsilon=eps);
+}
+
+#[test]
+fn slerp_endpoints() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E70);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(Unit);
+        let b: Qf = rng.sample(Unit);
+        assert_abs_diff_eq!(slerp(a, b, 0.0), a, epsilon=1e-12);
+        assert_same_rotation(slerp(a, b, 1.0), b, 1e-12);
+        assert_abs_diff_eq!(nlerp(a, b, 0.0), a, epsilon=1e-12);
+        assert_same_rotation(nlerp(a, b, 1.0), b, 1e-12);
+        assert_abs_diff_eq!(slerp(a, a, 0.3), a, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn slerp_constant_velocity() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E71);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(Unit);
+        let b: Qf = rng.sample(Unit);
+        let t = rng.sample(Uniform::new(0.0, 1.0));
+        let total = a.dot(b).abs().acos();
+        let c = slerp(a, b, t);
+        assert_abs_diff_eq!(c.norm(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(a.dot(c).acos(), t * total, epsilon=1e-9);
+        // Same path as nlerp.
+        let d = nlerp(a, b, t);
+        let e = slerp(a, b, a.dot(d).acos() / total);
+        assert_abs_diff_eq!(d, e, epsilon=1e-9);
+        // Slerp is a geodesic: `a * exp(t * ln(a⁻¹ * b))`.
+        let b = if a.dot(b) < 0.0 { -b } else { b };
+        assert_abs_diff_eq!(c, a * ((a.inv() * b).ln() * t).exp(), epsilon=1e-9);
+    }
+}
+
+#[test]
+fn shortest_arc() {
+    let a = Qf::from_axis_angle([0.0, 0.0, 1.0], 0.1);
+    let b = -Qf::from_axis_angle([0.0, 0.0, 1.0], 0.3);
+    assert_abs_diff_eq!(slerp(a, b, 0.5), Qf::from_axis_angle([0.0, 0.0, 1.0], 0.2), epsilon=1e-12);
+    assert_abs_diff_eq!(nlerp(a, b, 0.5), Qf::from_axis_angle([0.0, 0.0, 1.0], 0.2), epsilon=1e-12);
+}
+
+#[test]
+fn squad_interpolation() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E72);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let qs: [Qf; 4] = [rng.sample(Unit), rng.sample(Unit), rng.sample(Unit), rng.sample(Unit)];
+        let s1 = squad_control(qs[0], qs[1], qs[2]);
+        let s2 = squad_control(qs[1], qs[2], qs[3]);
+        let b = if qs[1].dot(qs[2]) < 0.0 { -qs[2] } else { qs[2] };
+        assert_abs_diff_eq!(squad(qs[1], s1, s2, b, 0.0), qs[1], epsilon=1e-12);
+        assert_abs_diff_eq!(squad(qs[1], s1, s2, b, 1.0), b, epsilon=1e-12);
+        let t = rng.sample(Uniform::new(0.0, 1.0));
+        assert_abs_diff_eq!(squad(qs[1], s1, s2, b, t).norm(), 1.0, epsilon=1e-12);
+    }
+    // Control points of evenly spaced rotations around one axis are keyframes themselves.
+    let qs: [Qf; 3] = [0.0, 0.5, 1.0].map(|a| Qf::from_axis_angle([1.0, 2.0, 3.0], a));
+    assert_abs_diff_eq!(squad_control(qs[0], qs[1], qs[2]), qs[1], epsilon=1e-12);
+}
+
+#[test]
+fn squad_opposite() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E74);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(Unit);
+        let t = rng.sample(Uniform::new(0.0, 1.0));
+        // Arc between opposite quaternions is chosen arbitrarily, but it is still a half of a great circle.
+        let q = squad(a, a, -a, -a, t);
+        assert_abs_diff_eq!(q.norm(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(q.dot(a), (t * core::f64::consts::PI).cos(), epsilon=1e-12);
+        assert_abs_diff_eq!(squad(a, a, -a, -a, 1.0), -a, epsilon=1e-12);
+        // Nearly opposite quaternions.
+        let b = (-a + rng.sample::<Qf, _>(StandardNormal) * 1e-9).normalize();
+        // `acos` is ill-conditioned near `-1`, so the angle is computed from the chord.
+        let theta = core::f64::consts::PI - 2.0 * ((a + b).norm() / 2.0).asin();
+        let q = squad(a, a, b, b, t);
+        assert_abs_diff_eq!(q.norm(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(q.dot(a), (t * theta).cos(), epsilon=1e-12);
+        assert_abs_diff_eq!(squad(a, a, b, b, 1.0), b, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn log_blending() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E73);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(Unit);
+        let b: Qf = rng.sample(Unit);
+        let t = rng.sample(Uniform::new(0.0, 1.0));
+        assert_abs_diff_eq!(log_blend(&[a, b], &[1.0 - t, t]), slerp(a, b, t), epsilon=1e-9);
+    }
+    assert_eq!(log_blend::<f64>(&[], &[]), Qf::one());
+}
+
+#[test]
+fn markley_average() {
+    let mut rng = XorShiftRng::seed_from_u64(0x1E74);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(Unit);
+        // Average of a single rotation regardless of the sign.
+        assert_same_rotation(average(&[a, -a, a], &[1.0, 2.0, 0.5]), a, 1e-12);
+
+        // Symmetric perturbations around `a` average back to `a`.
+        let d: Qf = rng.sample(Unit);
+        let d = Qf::new2(0.0, d.x(), d.y(), d.z()).normalize() * 0.1;
+        let (p, m) = (a * d.exp(), a * (-d).exp());
+        let avg = average(&[p, m], &[1.0, 1.0]);
+        assert_same_rotation(avg, a, 1e-12);
+        assert!(avg.dot(p) >= 0.0);
+        // Average of two rotations is the slerp midpoint.
+        let b: Qf = rng.sample(Unit);
+        let c = slerp(a, b, 0.02);
+        assert_same_rotation(average(&[a, c], &[1.0, 1.0]), slerp(a, c, 0.5), 1e-9);
+    }
+}
+
+#[test]
+#[should_panic]
+fn log_blending_mismatched_weights() {
+    log_blend(&[Qf::one(), Qf::one()], &[1.0]);
+}
+
+#[test]
+#[should_panic]
+fn markley_average_mismatched_weights() {
+    average(&[Qf::one()], &[0.5, 0.5]);
+}
@@ -1,3 +1,17 @@
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 mod base;
 mod complex;
 mod quaternion;
+mod euclid;
+mod transcendental;
+mod rotation;
+mod interp;
+mod gamma;
+mod representation;
+mod embed;
+mod refs;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "alloc")]
+mod batch;
@@ -96,3 +96,60 @@ fn derivation() {
         }
     }
 }
+
+#[test]
+fn exponent_logarithm() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(a.ln().exp(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.exp().norm(), a.w().exp(), epsilon=1e-12);
+        assert_abs_diff_eq!(a.exp() * (-a).exp(), Qf::one(), epsilon=1e-12);
+    }
+    assert_abs_diff_eq!(Qf::new2(-1.0, 0.0, 0.0, 0.0).ln(), Qf::i() * core::f64::consts::PI);
+}
+
+#[test]
+fn pure_exponent() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED5);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let u: Qf = rng.sample(Unit);
+        let u = Qf::new2(0.0, u.x(), u.y(), u.z()).normalize();
+        let t: f64 = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((u * t).exp(), u * t.sin() + t.cos(), epsilon=1e-12);
+    }
+}
+
+#[test]
+fn power() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED6);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(StandardNormal);
+        let b = a.sqrt();
+        assert_abs_diff_eq!(b * b, a, epsilon=1e-12);
+        let c = a.powf(1.0 / 3.0);
+        assert_abs_diff_eq!(c * c * c, a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.powq(Qf::one() * 2.0), a * a, epsilon=1e-10);
+    }
+}
+
+#[test]
+fn trigonometry() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED7);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = rng.sample::<Qf, _>(StandardNormal) * 0.25;
+        let (s, c) = (a.sin(), a.cos());
+        assert_abs_diff_eq!(s * s + c * c, Qf::one(), epsilon=1e-12);
+        assert_abs_diff_eq!(a.tan(), s / c, epsilon=1e-12);
+        assert_abs_diff_eq!(a.sin().asin(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.tan().atan(), a, epsilon=1e-12);
+
+        let (sh, ch) = (a.sinh(), a.cosh());
+        assert_abs_diff_eq!(ch * ch - sh * sh, Qf::one(), epsilon=1e-12);
+        assert_abs_diff_eq!(a.tanh(), sh / ch, epsilon=1e-12);
+        assert_abs_diff_eq!(a.sinh().asinh(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.tanh().atanh(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.acosh().cosh(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.acos().cos(), a, epsilon=1e-12);
+    }
+}
@@ -0,0 +1,38 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{prelude::*, *, random::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+
+macro_rules! test_refs { ($name:ident, $A:ty, $seed:expr) => (
+    #[test]
+    fn $name() {
+        let mut rng = XorShiftRng::seed_from_u64($seed);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: $A = rng.sample(StandardNormal);
+            let b: $A = rng.sample(NonZero);
+
+            assert_abs_diff_eq!(-&a, -a);
+            assert_abs_diff_eq!(&a + &b, a + b);
+            assert_abs_diff_eq!(&a - &b, a - b);
+            assert_abs_diff_eq!(&a * &b, a * b, epsilon=1e-12);
+            assert_abs_diff_eq!((&a).norm_sqr(), a.norm_sqr());
+            assert_abs_diff_eq!((&b).inv(), b.inv(), epsilon=1e-12);
+            assert_abs_diff_eq!(&a / &b, a / b, epsilon=1e-12);
+
+            let mut c = a;
+            c += &b;
+            c *= &b;
+            c -= &a;
+            c /= &b;
+            assert_abs_diff_eq!(c, ((a + b) * b - a) / b, epsilon=1e-12);
+        }
+    }
+) }
+
+test_refs!(complex, Complex<f64>, 0x4EF0);
+test_refs!(quaternion, Quaternion<f64>, 0x4EF1);
+test_refs!(octonion, Octonion<f64>, 0x4EF2);
+test_refs!(sedenion, Sedenion<f64>, 0x4EF3);
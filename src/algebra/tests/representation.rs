@@ -0,0 +1,69 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use rand_distr::StandardNormal;
+use ::approx::*;
+use crate::{prelude::*, *};
+
+
+const SAMPLE_ATTEMPTS: usize = 256;
+
+fn assert_apply<const N: usize>(m: [[f64; N]; N], x: &[f64], y: &[f64]) {
+    let z = m.map(|r| r.iter().zip(x).map(|(a, b)| a * b).sum::<f64>());
+    assert_abs_diff_eq!(&z[..], y, epsilon=1e-12);
+}
+fn mul<U: Zero + Clone + core::ops::Mul<Output=U>>(a: [[U; 2]; 2], b: [[U; 2]; 2]) -> [[U; 2]; 2] {
+    core::array::from_fn(|i| core::array::from_fn(|j| {
+        a[i][0].clone() * b[0][j].clone() + a[i][1].clone() * b[1][j].clone()
+    }))
+}
+
+#[test]
+fn complex() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAB00);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let (a, b): (Complex<f64>, Complex<f64>) = (rng.sample(StandardNormal), rng.sample(StandardNormal));
+        assert_apply(a.to_real_matrix(), b.as_slice(), (a * b).as_slice());
+        assert_eq!(Complex::from_real_matrix(a.to_real_matrix(), 0.0), Some(a));
+    }
+    assert_eq!(Complex::from_real_matrix([[1.0, 0.0], [0.0, 2.0]], 1e-12), None);
+}
+
+#[test]
+fn quaternion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAB01);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let (a, b): (Quaternion<f64>, Quaternion<f64>) = (rng.sample(StandardNormal), rng.sample(StandardNormal));
+        assert_apply(a.to_left_mul_matrix(), b.as_slice(), (a * b).as_slice());
+        assert_apply(a.to_right_mul_matrix(), b.as_slice(), (b * a).as_slice());
+        assert_eq!(Quaternion::from_left_mul_matrix(a.to_left_mul_matrix(), 0.0), Some(a));
+        assert_eq!(Quaternion::from_right_mul_matrix(a.to_right_mul_matrix(), 0.0), Some(a));
+        assert_eq!(Quaternion::from_left_mul_matrix(a.to_right_mul_matrix(), 1e-12), None);
+    }
+}
+
+#[test]
+fn quaternion_complex() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAB02);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let (a, b): (Quaternion<f64>, Quaternion<f64>) = (rng.sample(StandardNormal), rng.sample(StandardNormal));
+        let (ab, m) = ((a * b).to_complex_matrix(), mul(a.to_complex_matrix(), b.to_complex_matrix()));
+        for (x, y) in ab.iter().flatten().zip(m.iter().flatten()) {
+            assert_abs_diff_eq!(x, y, epsilon=1e-12);
+        }
+        assert_eq!(Quaternion::from_complex_matrix(a.to_complex_matrix(), 0.0), Some(a));
+    }
+    let (one, i) = (Complex::<f64>::one(), Complex::i());
+    assert_eq!(Quaternion::from_complex_matrix([[one, i], [one, one]], 1e-12), None);
+}
+
+#[test]
+fn octonion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAB03);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let (a, b): (Octonion<f64>, Octonion<f64>) = (rng.sample(StandardNormal), rng.sample(StandardNormal));
+        assert_apply(a.to_left_mul_matrix(), b.as_slice(), (a * b).as_slice());
+        assert_apply(a.to_right_mul_matrix(), b.as_slice(), (b * a).as_slice());
+        assert_eq!(Octonion::from_left_mul_matrix(a.to_left_mul_matrix(), 0.0), Some(a));
+        assert_eq!(Octonion::from_right_mul_matrix(a.to_right_mul_matrix(), 0.0), Some(a));
+    }
+}
@@ -0,0 +1,120 @@
+use rand::{prelude::*};
+use rand_distr::{Uniform};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{prelude::*, *, random::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Qf = Quaternion<f64>;
+
+const SEQS: [EulerSeq; 12] = [
+    EulerSeq::XYZ, EulerSeq::XZY, EulerSeq::YXZ, EulerSeq::YZX, EulerSeq::ZXY, EulerSeq::ZYX,
+    EulerSeq::XYX, EulerSeq::XZX, EulerSeq::YXY, EulerSeq::YZY, EulerSeq::ZXZ, EulerSeq::ZYZ,
+];
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn mat_vec(m: [[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    let mut r = [0.0; 3];
+    for i in 0..3 {
+        r[i] = (0..3).map(|j| m[i][j] * v[j]).sum();
+    }
+    r
+}
+
+/// Check that both quaternions represent the same rotation.
+fn assert_same_rotation(a: Qf, b: Qf) {
+    let (a, b) = (a.normalize(), b.normalize());
+    let b = if a.dot(b) < 0.0 { -b } else { b };
+    assert_abs_diff_eq!(a, b, epsilon=1e-10);
+}
+
+#[test]
+fn axis_angle() {
+    let pi = core::f64::consts::PI;
+    let q = Qf::from_axis_angle([0.0, 0.0, 2.0], pi / 2.0);
+    assert_abs_diff_eq!(&q.rotate_vector([1.0, 0.0, 0.0])[..], &[0.0, 1.0, 0.0][..], epsilon=1e-15);
+
+    let mut rng = XorShiftRng::seed_from_u64(0x7070);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let q: Qf = rng.sample(Unit);
+        let (axis, angle) = q.to_axis_angle();
+        assert!((0.0..=2.0 * pi).contains(&angle));
+        assert_abs_diff_eq!(axis.iter().map(|x| x * x).sum::<f64>(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(Qf::from_axis_angle(axis, angle), q, epsilon=1e-12);
+    }
+    assert_eq!(Qf::one().to_axis_angle(), ([1.0, 0.0, 0.0], 0.0));
+    assert_eq!(Qf::from_axis_angle([0.0; 3], 1.0), Qf::one());
+}
+
+#[test]
+fn rotation_matrix() {
+    let mut rng = XorShiftRng::seed_from_u64(0x7071);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let q: Qf = rng.sample(NonZero);
+        let v = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        let m = q.to_rotation_matrix();
+        assert_abs_diff_eq!(&mat_vec(m, v)[..], &q.rotate_vector(v)[..], epsilon=1e-12);
+        assert_same_rotation(Qf::from_rotation_matrix(m), q);
+    }
+    // Rotations by π around the coordinate axes have zero trace.
+    for axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, -1.0, 0.0]].iter() {
+        let q = Qf::from_axis_angle(*axis, core::f64::consts::PI);
+        assert_same_rotation(Qf::from_rotation_matrix(q.to_rotation_matrix()), q);
+    }
+}
+
+#[test]
+fn euler_angles() {
+    let pi = core::f64::consts::PI;
+    let mut rng = XorShiftRng::seed_from_u64(0x7072);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let q: Qf = rng.sample(Unit);
+        for &seq in SEQS.iter() {
+            for &frame in FRAMES.iter() {
+                let angles = q.to_euler_angles(seq, frame);
+                let (lo, hi) = if seq.axes()[0] == seq.axes()[2] { (0.0, pi) } else { (-pi / 2.0, pi / 2.0) };
+                assert!(angles[1] >= lo - 1e-12 && angles[1] <= hi + 1e-12);
+                assert_same_rotation(Qf::from_euler_angles(seq, frame, angles), q);
+            }
+        }
+    }
+}
+
+#[test]
+fn euler_angles_roundtrip() {
+    let mut rng = XorShiftRng::seed_from_u64(0x7073);
+    let pi = core::f64::consts::PI;
+    for _ in 0..SAMPLE_ATTEMPTS {
+        for &seq in SEQS.iter() {
+            for &frame in FRAMES.iter() {
+                let mid = if seq.axes()[0] == seq.axes()[2] { Uniform::new(0.1, pi - 0.1) } else { Uniform::new(-pi / 2.0 + 0.1, pi / 2.0 - 0.1) };
+                let outer = Uniform::new(-pi, pi);
+                let angles = [rng.sample(outer), rng.sample(mid), rng.sample(outer)];
+                let q = Qf::from_euler_angles(seq, frame, angles);
+                assert_abs_diff_eq!(&q.to_euler_angles(seq, frame)[..], &angles[..], epsilon=1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn euler_gimbal_lock() {
+    let pi = core::f64::consts::PI;
+    for &seq in SEQS.iter() {
+        for &frame in FRAMES.iter() {
+            for &mid in [0.0, pi, pi / 2.0, -pi / 2.0].iter() {
+                let q = Qf::from_euler_angles(seq, frame, [0.3, mid, -0.7]);
+                assert_same_rotation(Qf::from_euler_angles(seq, frame, q.to_euler_angles(seq, frame)), q);
+            }
+        }
+    }
+}
+
+#[test]
+fn intrinsic_extrinsic() {
+    let angles = [0.1, 0.2, 0.3];
+    let a = Qf::from_euler_angles(EulerSeq::XYZ, EulerFrame::Intrinsic, angles);
+    let b = Qf::from_euler_angles(EulerSeq::ZYX, EulerFrame::Extrinsic, [0.3, 0.2, 0.1]);
+    assert_abs_diff_eq!(a, b, epsilon=1e-15);
+}
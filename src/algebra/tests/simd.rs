@@ -0,0 +1,96 @@
+use rand::{prelude::*};
+use rand_distr::{StandardNormal};
+use rand_xorshift::XorShiftRng;
+use crate::{*, simd::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 1024;
+
+
+/// Values which may break the exact order of operations: zeros of both signs, infinities and large magnitudes.
+fn special<R: Rng>(rng: &mut R) -> f64 {
+    match rng.gen_range(0, 8) {
+        0 => 0.0,
+        1 => -0.0,
+        2 => f64::INFINITY,
+        3 => 1e300 * rng.sample::<f64, _>(StandardNormal),
+        _ => rng.sample(StandardNormal),
+    }
+}
+
+#[test]
+fn quaternion_f32() {
+    let mut rng = XorShiftRng::seed_from_u64(0x51D0);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let mut v = [[0.0f32; 4]; 2];
+        for x in v.iter_mut().flatten() {
+            *x = special(&mut rng) as f32;
+        }
+        let (a, b) = (Quaternion::from_slice(&v[0]), Quaternion::from_slice(&v[1]));
+        let (p, q) = (a * b, a.mul_simd(b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+    }
+}
+
+#[test]
+fn quaternion_f64() {
+    let mut rng = XorShiftRng::seed_from_u64(0x51D1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let mut v = [[0.0f64; 4]; 2];
+        for x in v.iter_mut().flatten() {
+            *x = special(&mut rng);
+        }
+        let (a, b) = (Quaternion::from_slice(&v[0]), Quaternion::from_slice(&v[1]));
+        let (p, q) = (a * b, a.mul_simd(b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+    }
+}
+
+#[test]
+fn complex_f32() {
+    let mut rng = XorShiftRng::seed_from_u64(0x51D2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let mut v = [[0.0f32; 2]; 2];
+        for x in v.iter_mut().flatten() {
+            *x = special(&mut rng) as f32;
+        }
+        let (a, b) = (Complex::from_slice(&v[0]), Complex::from_slice(&v[1]));
+        let (p, q) = (a * b, a.mul_simd(b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+    }
+}
+
+/// The portable fallback is used only on other architectures, so it is checked here directly.
+#[test]
+fn scalar_fallback() {
+    let mut rng = XorShiftRng::seed_from_u64(0x51D3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let mut v = [[0.0f64; 4]; 2];
+        for x in v.iter_mut().flatten() {
+            *x = special(&mut rng);
+        }
+        let (a, b) = (Quaternion::from_array(v[0]), Quaternion::from_array(v[1]));
+        let (p, q) = (a * b, scalar::quaternion_f64(&a, &b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+
+        let (a, b) = (Quaternion::from_array(v[0].map(|x| x as f32)), Quaternion::from_array(v[1].map(|x| x as f32)));
+        let (p, q) = (a * b, scalar::quaternion_f32(&a, &b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+
+        let (a, b) = (Complex::new(v[0][0] as f32, v[0][1] as f32), Complex::new(v[1][0] as f32, v[1][1] as f32));
+        let (p, q) = (a * b, scalar::complex_f32(&a, &b));
+        for (x, y) in p.iter().zip(q.iter()) {
+            assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use core::fmt::Debug;
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use ::approx::*;
+use crate::{prelude::*, *, random::*};
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+
+
+fn check_identities<A>(seed: u64) where
+    A: Algebra<f64> + Transcendental<f64> + AbsDiffEq<Epsilon=f64> + Debug + Copy,
+    StandardNormal: Distribution<A>,
+{
+    let mut rng = XorShiftRng::seed_from_u64(seed);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: A = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(a.ln().exp(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.exp() * (-a).exp(), A::one(), epsilon=1e-12);
+        assert_abs_diff_eq!(a.sqrt() * a.sqrt(), a, epsilon=1e-12);
+        assert_abs_diff_eq!(a.powf(3.0), a * a * a, epsilon=1e-10);
+
+        let b = a * 0.25;
+        let (s, c) = (b.sin(), b.cos());
+        assert_abs_diff_eq!(s * s + c * c, A::one(), epsilon=1e-12);
+        assert_abs_diff_eq!(b.tan(), s / c, epsilon=1e-12);
+        assert_abs_diff_eq!(b.asin().sin(), b, epsilon=1e-12);
+        assert_abs_diff_eq!(b.acos().cos(), b, epsilon=1e-12);
+        assert_abs_diff_eq!(b.atan().tan(), b, epsilon=1e-12);
+        let (sh, ch) = (b.sinh(), b.cosh());
+        assert_abs_diff_eq!(ch * ch - sh * sh, A::one(), epsilon=1e-12);
+        assert_abs_diff_eq!(b.tanh(), sh / ch, epsilon=1e-12);
+        assert_abs_diff_eq!(b.asinh().sinh(), b, epsilon=1e-12);
+        assert_abs_diff_eq!(b.acosh().cosh(), b, epsilon=1e-12);
+        assert_abs_diff_eq!(b.atanh().tanh(), b, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn complex() {
+    check_identities::<Complex<f64>>(0xACE0);
+}
+
+#[test]
+fn quaternion() {
+    check_identities::<Quaternion<f64>>(0xACE1);
+}
+
+#[test]
+fn octonion() {
+    check_identities::<Octonion<f64>>(0xACE2);
+}
+
+#[test]
+fn sedenion() {
+    check_identities::<Sedenion<f64>>(0xACE3);
+}
+
+#[test]
+fn real_axis() {
+    let pi = core::f64::consts::PI;
+    let o = Octonion::<f64>::one() * -1.0;
+    let mut e1 = Octonion::zero();
+    *e1.re_mut().re_mut().im_mut() = pi;
+    assert_abs_diff_eq!(o.ln(), e1, epsilon=1e-15);
+    assert_abs_diff_eq!(o.arg(), pi);
+    assert_abs_diff_eq!((Octonion::<f64>::one() * 4.0).sqrt(), Octonion::one() * 2.0);
+}
+
+#[test]
+fn scalar() {
+    assert_abs_diff_eq!(Transcendental::exp(1.0f64), core::f64::consts::E);
+}
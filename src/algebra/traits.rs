@@ -1,7 +1,7 @@
 use core::ops::{
     Neg, Add, Sub, Mul, Div,
 };
-use num_traits::{Zero, One};
+use num_traits::{Zero, One, Float};
 
 
 /// Something that can be conjugated.
@@ -47,6 +47,42 @@ pub trait NormL1 {
     fn norm_l1(self) -> Self::Output;
 }
 
+/// Elementary transcendental functions over scalar type `T`.
+pub trait Transcendental<T = Self>: Sized {
+    /// Computes `e^(self)`, where `e` is the base of the natural logarithm.
+    fn exp(self) -> Self;
+    /// Computes the principal value of natural logarithm of `self`.
+    fn ln(self) -> Self;
+    /// Computes the principal value of the square root of `self`.
+    fn sqrt(self) -> Self;
+    /// Raises `self` to a floating point power.
+    fn powf(self, exp: T) -> Self;
+    /// Computes the sine of `self`.
+    fn sin(self) -> Self;
+    /// Computes the cosine of `self`.
+    fn cos(self) -> Self;
+    /// Computes the tangent of `self`.
+    fn tan(self) -> Self;
+    /// Computes the principal value of the inverse sine of `self`.
+    fn asin(self) -> Self;
+    /// Computes the principal value of the inverse cosine of `self`.
+    fn acos(self) -> Self;
+    /// Computes the principal value of the inverse tangent of `self`.
+    fn atan(self) -> Self;
+    /// Computes the hyperbolic sine of `self`.
+    fn sinh(self) -> Self;
+    /// Computes the hyperbolic cosine of `self`.
+    fn cosh(self) -> Self;
+    /// Computes the hyperbolic tangent of `self`.
+    fn tanh(self) -> Self;
+    /// Computes the principal value of the inverse hyperbolic sine of `self`.
+    fn asinh(self) -> Self;
+    /// Computes the principal value of the inverse hyperbolic cosine of `self`.
+    fn acosh(self) -> Self;
+    /// Computes the principal value of the inverse hyperbolic tangent of `self`.
+    fn atanh(self) -> Self;
+}
+
 /// Algebra over some base.
 pub trait Algebra<T: Algebra = Self>:
     Neg<Output=Self> +
@@ -64,6 +100,36 @@ pub trait Algebra<T: Algebra = Self>:
     NormSqr<Output=T>
 {}
 
+/// Marker of algebra with commutative multiplication, `a b = b a`.
+pub trait Commutative {}
+
+/// Marker of algebra with associative multiplication, `(a b) c = a (b c)`.
+///
+/// Composition of Moebius transformations requires associative coefficients:
+///
+/// ```
+/// use hcomplex::{Quaternion, transform::*};
+/// let a = Moebius::<Quaternion<f64>>::identity();
+/// let _ = a.chain(a);
+/// ```
+///
+/// so it doesn't compile for octonions:
+///
+/// ```compile_fail
+/// use hcomplex::{Octonion, transform::*};
+/// let a = Moebius::<Octonion<f64>>::identity();
+/// let _ = a.chain(a);
+/// ```
+pub trait Associative: Alternative {}
+
+/// Marker of alternative algebra, i.e. any subalgebra generated by two elements is associative, `(a a) b = a (a b)` and `(a b) b = a (b b)`.
+pub trait Alternative {}
+
+/// Marker of division algebra, i.e. algebra without zero divisors, where every nonzero element has an inverse.
+///
+/// Division `a / b` computed as `a b⁻¹` is exact only for such algebras.
+pub trait DivisionAlgebra {}
+
 macro_rules! derive_primitive { ($T:ident) => (
     impl Conj for $T {
         fn conj(self) -> Self {
@@ -82,6 +148,12 @@ macro_rules! derive_primitive { ($T:ident) => (
             self*self
         }
     }
+    impl NormSqr for &$T {
+        type Output = $T;
+        fn norm_sqr(self) -> $T {
+            self*self
+        }
+    }
     impl Norm for $T {
         type Output = Self;
         fn norm(self) -> Self {
@@ -95,6 +167,9 @@ macro_rules! derive_primitive { ($T:ident) => (
         }
     }
     impl Algebra for $T {}
+    impl Commutative for $T {}
+    impl Associative for $T {}
+    impl Alternative for $T {}
 ) }
 
 derive_primitive!(i8);
@@ -104,3 +179,30 @@ derive_primitive!(i64);
 
 derive_primitive!(f32);
 derive_primitive!(f64);
+
+macro_rules! derive_transcendental { ($T:ident) => (
+    impl Transcendental for $T {
+        fn exp(self) -> Self { Float::exp(self) }
+        fn ln(self) -> Self { Float::ln(self) }
+        fn sqrt(self) -> Self { Float::sqrt(self) }
+        fn powf(self, exp: Self) -> Self { Float::powf(self, exp) }
+        fn sin(self) -> Self { Float::sin(self) }
+        fn cos(self) -> Self { Float::cos(self) }
+        fn tan(self) -> Self { Float::tan(self) }
+        fn asin(self) -> Self { Float::asin(self) }
+        fn acos(self) -> Self { Float::acos(self) }
+        fn atan(self) -> Self { Float::atan(self) }
+        fn sinh(self) -> Self { Float::sinh(self) }
+        fn cosh(self) -> Self { Float::cosh(self) }
+        fn tanh(self) -> Self { Float::tanh(self) }
+        fn asinh(self) -> Self { Float::asinh(self) }
+        fn acosh(self) -> Self { Float::acosh(self) }
+        fn atanh(self) -> Self { Float::atanh(self) }
+    }
+) }
+
+derive_transcendental!(f32);
+derive_transcendental!(f64);
+
+impl DivisionAlgebra for f32 {}
+impl DivisionAlgebra for f64 {}
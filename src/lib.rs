@@ -1,6 +1,8 @@
 #![no_std]
 #[cfg(feature = "std")]
 extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 
 mod algebra;
@@ -8,7 +10,9 @@ pub use algebra::*;
 
 pub mod transform;
 
+pub mod matrix;
+
 pub mod prelude {
     pub use num_traits::{One, Zero, Inv};
-    pub use crate::{Conj, Dot, NormSqr, Norm, NormL1, Algebra};
+    pub use crate::{Conj, Dot, NormSqr, Norm, NormL1, Transcendental, Algebra, Commutative, Associative, Alternative, DivisionAlgebra, flat::Flat};
 }
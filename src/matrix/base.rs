@@ -0,0 +1,182 @@
+use core::{
+    array,
+    ops::{Neg, Add, Sub, Mul, Index, IndexMut},
+};
+use num_traits::{Float, Zero, One};
+use crate::*;
+
+
+/// Matrix with `R` rows and `C` columns over an algebra `U`.
+///
+/// Elements are not expected to commute, so the order of factors is always preserved:
+/// `(A * B)ᵢⱼ = Σ Aᵢₖ Bₖⱼ`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix<U, const R: usize, const C: usize> {
+    data: [[U; C]; R],
+}
+
+/// Square matrix.
+pub type SquareMatrix<U, const N: usize> = Matrix<U, N, N>;
+
+impl<U, const R: usize, const C: usize> From<[[U; C]; R]> for Matrix<U, R, C> {
+    fn from(data: [[U; C]; R]) -> Self {
+        Self { data }
+    }
+}
+impl<U, const R: usize, const C: usize> From<Matrix<U, R, C>> for [[U; C]; R] {
+    fn from(m: Matrix<U, R, C>) -> Self {
+        m.data
+    }
+}
+
+impl<U, const R: usize, const C: usize> Matrix<U, R, C> {
+    /// Create from array of rows.
+    pub fn new(data: [[U; C]; R]) -> Self {
+        Self::from(data)
+    }
+    /// Create by calling `f(i, j)` for each element.
+    pub fn from_fn<F: FnMut(usize, usize) -> U>(mut f: F) -> Self {
+        Self::new(array::from_fn(|i| array::from_fn(|j| f(i, j))))
+    }
+    /// Array of rows.
+    pub fn rows(&self) -> &[[U; C]; R] {
+        &self.data
+    }
+    /// Mutable array of rows.
+    pub fn rows_mut(&mut self) -> &mut [[U; C]; R] {
+        &mut self.data
+    }
+    /// Apply `f` to each element.
+    pub fn map<V, F: FnMut(U) -> V>(self, mut f: F) -> Matrix<V, R, C> {
+        Matrix::new(self.data.map(|row| row.map(&mut f)))
+    }
+}
+
+impl<U, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<U, R, C> {
+    type Output = U;
+    fn index(&self, (i, j): (usize, usize)) -> &U {
+        &self.data[i][j]
+    }
+}
+impl<U, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<U, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut U {
+        &mut self.data[i][j]
+    }
+}
+
+impl<U: Clone, const R: usize, const C: usize> Matrix<U, R, C> {
+    /// Transposed matrix.
+    pub fn transpose(&self) -> Matrix<U, C, R> {
+        Matrix::from_fn(|i, j| self[(j, i)].clone())
+    }
+    /// Conjugate-transposed (Hermitian adjoint) matrix.
+    pub fn conj_transpose(&self) -> Matrix<U, C, R> where U: Conj {
+        Matrix::from_fn(|i, j| self[(j, i)].clone().conj())
+    }
+    /// Multiply each element by `s` from the left, i.e. `s A`.
+    pub fn scale_left(&self, s: U) -> Self where U: Mul<Output=U> {
+        Self::from_fn(|i, j| s.clone() * self[(i, j)].clone())
+    }
+    /// Multiply each element by `s` from the right, i.e. `A s`.
+    pub fn scale_right(&self, s: U) -> Self where U: Mul<Output=U> {
+        Self::from_fn(|i, j| self[(i, j)].clone() * s.clone())
+    }
+}
+
+impl<U, const R: usize, const C: usize> Matrix<U, R, C> where U: Zero + Add<Output=U> + Mul<Output=U> + Clone {
+    /// Left action on a column vector, `A v`.
+    pub fn mul_vec(&self, v: [U; C]) -> [U; R] {
+        array::from_fn(|i| (0..C).fold(U::zero(), |s, k| s + self[(i, k)].clone() * v[k].clone()))
+    }
+    /// Right action on a row vector, `vᵀ A`.
+    pub fn vec_mul(&self, v: [U; R]) -> [U; C] {
+        array::from_fn(|j| (0..R).fold(U::zero(), |s, k| s + v[k].clone() * self[(k, j)].clone()))
+    }
+}
+
+impl<U: Neg<Output=U>, const R: usize, const C: usize> Neg for Matrix<U, R, C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|x| -x)
+    }
+}
+impl<U: Add<Output=U>, const R: usize, const C: usize> Add for Matrix<U, R, C> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut rhs = IntoIterator::into_iter(other.data).flatten();
+        self.map(|x| x + rhs.next().unwrap())
+    }
+}
+impl<U: Sub<Output=U>, const R: usize, const C: usize> Sub for Matrix<U, R, C> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        let mut rhs = IntoIterator::into_iter(other.data).flatten();
+        self.map(|x| x - rhs.next().unwrap())
+    }
+}
+impl<U, const R: usize, const K: usize, const C: usize> Mul<Matrix<U, K, C>> for Matrix<U, R, K> where U: Zero + Add<Output=U> + Mul<Output=U> + Clone {
+    type Output = Matrix<U, R, C>;
+    fn mul(self, other: Matrix<U, K, C>) -> Matrix<U, R, C> {
+        Matrix::from_fn(|i, j| (0..K).fold(U::zero(), |s, k| s + self[(i, k)].clone() * other[(k, j)].clone()))
+    }
+}
+
+impl<U: Zero + Add<Output=U>, const R: usize, const C: usize> Zero for Matrix<U, R, C> {
+    fn zero() -> Self {
+        Self::from_fn(|_, _| U::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.data.iter().flatten().all(|x| x.is_zero())
+    }
+}
+impl<U: Zero + One + Add<Output=U> + Mul<Output=U> + Clone, const N: usize> One for Matrix<U, N, N> {
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<U: Zero + One, const N: usize> Matrix<U, N, N> {
+    /// Identity matrix.
+    pub fn identity() -> Self {
+        Self::from_fn(|i, j| if i == j { U::one() } else { U::zero() })
+    }
+}
+impl<U: Zero + Add<Output=U> + Clone, const N: usize> Matrix<U, N, N> {
+    /// Sum of diagonal elements.
+    pub fn trace(&self) -> U {
+        (0..N).fold(U::zero(), |s, i| s + self[(i, i)].clone())
+    }
+}
+
+/// Index of the element of the largest norm in `k`-th column starting from `k`-th row.
+pub(crate) fn pivot<T: Float, U: NormSqr<Output=T> + Clone, const N: usize>(m: &Matrix<U, N, N>, k: usize) -> usize {
+    (k + 1..N).fold(k, |p, i| if m[(i, k)].clone().norm_sqr() > m[(p, k)].clone().norm_sqr() { i } else { p })
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone, const N: usize> Matrix<Construct<T, V>, N, N> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// Inverse matrix computed by Gauss–Jordan elimination with partial pivoting, `None` if the matrix is singular.
+    ///
+    /// Rows are only multiplied from the left, so the result is correct for any associative division algebra.
+    pub fn inverse(&self) -> Option<Self> {
+        let (mut a, mut b) = (self.clone(), Self::identity());
+        for k in 0..N {
+            let p = pivot(&a, k);
+            if a[(p, k)].clone().norm_sqr() <= T::zero() {
+                return None;
+            }
+            a.data.swap(k, p);
+            b.data.swap(k, p);
+            let f = Construct::<T, V>::one() / a[(k, k)].clone();
+            a.data[k] = a.data[k].clone().map(|x| f.clone() * x);
+            b.data[k] = b.data[k].clone().map(|x| f.clone() * x);
+            for i in (0..N).filter(|&i| i != k) {
+                let f = a[(i, k)].clone();
+                for j in 0..N {
+                    a[(i, j)] = a[(i, j)].clone() - f.clone() * a[(k, j)].clone();
+                    b[(i, j)] = b[(i, j)].clone() - f.clone() * b[(k, j)].clone();
+                }
+            }
+        }
+        Some(b)
+    }
+}
@@ -0,0 +1,172 @@
+use core::array;
+use num_traits::{Float, Zero, One};
+use crate::*;
+use super::*;
+
+
+/// LU decomposition with partial pivoting, `P A = L U`.
+///
+/// `L` is unit lower triangular and `U` is upper triangular.
+/// Elimination only adds left multiples of rows, so it is valid for any associative division algebra.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<U, const N: usize> {
+    /// `L` below the diagonal and `U` on and above it.
+    lu: Matrix<U, N, N>,
+    perm: [usize; N],
+    odd: bool,
+}
+
+/// QR decomposition `A = Q R`, where `Q` is unitary and `R` is upper triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Qr<U, const R: usize, const C: usize> {
+    pub q: Matrix<U, R, R>,
+    pub r: Matrix<U, R, C>,
+}
+
+/// LU elimination of the `n × n` row-major matrix `a` in place, returns whether the number of row swaps is odd.
+pub(super) fn lu_in_place<T: Float + Algebra, V: Algebra<T> + Clone>(a: &mut [Construct<T, V>], n: usize, perm: &mut [usize]) -> bool where
+    Construct<T, V>: Associative + DivisionAlgebra,
+{
+    let mut odd = false;
+    for k in 0..n {
+        let p = (k + 1..n).fold(k, |p, i| if a[i * n + k].clone().norm_sqr() > a[p * n + k].clone().norm_sqr() { i } else { p });
+        if p != k {
+            for j in 0..n {
+                a.swap(k * n + j, p * n + j);
+            }
+            perm.swap(k, p);
+            odd = !odd;
+        }
+        if a[k * n + k].clone().norm_sqr().is_zero() {
+            continue;
+        }
+        let f = Construct::<T, V>::one() / a[k * n + k].clone();
+        for i in k + 1..n {
+            let l = a[i * n + k].clone() * f.clone();
+            for j in k + 1..n {
+                a[i * n + j] = a[i * n + j].clone() - l.clone() * a[k * n + j].clone();
+            }
+            a[i * n + k] = l;
+        }
+    }
+    odd
+}
+
+/// Solve `L U x = b` in place, where `L` and `U` are packed in `lu` and `x` initially contains permuted `b`.
+pub(super) fn lu_solve_in_place<T: Float + Algebra, V: Algebra<T> + Clone>(lu: &[Construct<T, V>], n: usize, x: &mut [Construct<T, V>]) where
+    Construct<T, V>: Associative + DivisionAlgebra,
+{
+    for i in 0..n {
+        for k in 0..i {
+            x[i] = x[i].clone() - lu[i * n + k].clone() * x[k].clone();
+        }
+    }
+    for i in (0..n).rev() {
+        for k in i + 1..n {
+            x[i] = x[i].clone() - lu[i * n + k].clone() * x[k].clone();
+        }
+        x[i] = Construct::<T, V>::one() / lu[i * n + i].clone() * x[i].clone();
+    }
+}
+
+/// Householder QR of the `rows × cols` row-major matrix `r` in place, accumulating the reflections into `rows × rows` matrix `q`.
+///
+/// Reflection vectors are chosen so that `v* x` is real, hence the reflections are also valid for quaternions.
+pub(super) fn qr_in_place<T: Float + Algebra, V: Algebra<T> + Clone>(r: &mut [Construct<T, V>], rows: usize, cols: usize, q: &mut [Construct<T, V>]) where
+    Construct<T, V>: Associative + DivisionAlgebra,
+{
+    let two = T::one() + T::one();
+    for k in 0..rows.min(cols) {
+        let xn = (k..rows).fold(T::zero(), |s, i| s + r[i * cols + k].clone().norm_sqr()).sqrt();
+        if xn.is_zero() {
+            continue;
+        }
+        // `v = x - α e₁`, where `α = -u |x|` and `u` is the phase of the leading element.
+        // Only `v[k]` differs from the `k`-th column, so the column itself holds the rest of `v` until it is reflected.
+        let x0 = r[k * cols + k].clone();
+        let x0n = x0.clone().norm_sqr().sqrt();
+        let u = if x0n.is_zero() { Construct::one() } else { x0.clone() / x0n };
+        let vk = x0 + u.clone() * xn;
+        let v = |r: &[Construct<T, V>], i: usize| if i == k { vk.clone() } else { r[i * cols + k].clone() };
+        let vn = (k..rows).fold(T::zero(), |s, i| s + v(r, i).norm_sqr());
+        if vn.is_zero() {
+            continue;
+        }
+        let f = two / vn;
+        // `Q <- Q H`
+        for i in 0..rows {
+            let s = (k..rows).fold(Construct::<T, V>::zero(), |s, l| s + q[i * rows + l].clone() * v(r, l)) * f;
+            for j in k..rows {
+                q[i * rows + j] = q[i * rows + j].clone() - s.clone() * v(r, j).conj();
+            }
+        }
+        // `R <- H R`
+        for j in k + 1..cols {
+            let s = (k..rows).fold(Construct::<T, V>::zero(), |s, i| s + v(r, i).conj() * r[i * cols + j].clone()) * f;
+            for i in k..rows {
+                r[i * cols + j] = r[i * cols + j].clone() - v(r, i) * s.clone();
+            }
+        }
+        // `H x = α e₁`
+        r[k * cols + k] = -(u * xn);
+        for i in k + 1..rows {
+            r[i * cols + k] = Construct::zero();
+        }
+    }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone, const N: usize> Matrix<Construct<T, V>, N, N> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// LU decomposition with partial pivoting.
+    pub fn lu(&self) -> Lu<Construct<T, V>, N> {
+        let mut lu = self.clone();
+        let mut perm = array::from_fn(|i| i);
+        let odd = lu_in_place(lu.rows_mut().as_flattened_mut(), N, &mut perm);
+        Lu { lu, perm, odd }
+    }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone, const N: usize> Lu<Construct<T, V>, N> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// Unit lower triangular factor.
+    pub fn l(&self) -> Matrix<Construct<T, V>, N, N> {
+        Matrix::from_fn(|i, j| match i.cmp(&j) {
+            core::cmp::Ordering::Greater => self.lu[(i, j)].clone(),
+            core::cmp::Ordering::Equal => Construct::one(),
+            core::cmp::Ordering::Less => Construct::zero(),
+        })
+    }
+    /// Upper triangular factor.
+    pub fn u(&self) -> Matrix<Construct<T, V>, N, N> {
+        Matrix::from_fn(|i, j| if i <= j { self.lu[(i, j)].clone() } else { Construct::zero() })
+    }
+    /// Row permutation: `i`-th row of `P A` is `perm[i]`-th row of `A`.
+    pub fn permutation(&self) -> [usize; N] {
+        self.perm
+    }
+    /// Whether the number of row swaps is odd.
+    pub fn is_odd(&self) -> bool {
+        self.odd
+    }
+    /// Whether the decomposed matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.lu[(i, i)].clone().norm_sqr().is_zero())
+    }
+    /// Solve `A x = b`, `None` if the matrix is singular.
+    pub fn solve(&self, b: [Construct<T, V>; N]) -> Option<[Construct<T, V>; N]> {
+        if self.is_singular() {
+            return None;
+        }
+        let mut x: [Construct<T, V>; N] = array::from_fn(|i| b[self.perm[i]].clone());
+        lu_solve_in_place(self.lu.rows().as_flattened(), N, &mut x);
+        Some(x)
+    }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone, const R: usize, const C: usize> Matrix<Construct<T, V>, R, C> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// QR decomposition using Householder reflections `H = I - 2 v v* / (v* v)`.
+    pub fn qr(&self) -> Qr<Construct<T, V>, R, C> {
+        let mut r = self.clone();
+        let mut q = Matrix::<Construct<T, V>, R, R>::identity();
+        qr_in_place(r.rows_mut().as_flattened_mut(), R, C, q.rows_mut().as_flattened_mut());
+        Qr { q, r }
+    }
+}
@@ -0,0 +1,54 @@
+use num_traits::{Float, One};
+use crate::*;
+use super::*;
+
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone, const N: usize> Matrix<Construct<T, V>, N, N> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// Dieudonné determinant, which is a non-negative real number for matrices over a non-commutative division algebra.
+    ///
+    /// For complex matrices it is equal to `|det|`.
+    pub fn dieudonne_det(&self) -> T {
+        let u = self.lu().u();
+        (0..N).fold(T::one(), |d, i| d * u[(i, i)].clone().norm_sqr().sqrt())
+    }
+    /// Study determinant, the square of [`dieudonne_det`](Self::dieudonne_det).
+    ///
+    /// For quaternion matrices it is equal to the determinant of the complex adjoint matrix.
+    pub fn study_det(&self) -> T {
+        let d = self.dieudonne_det();
+        d * d
+    }
+}
+
+impl<T: Float + Algebra, const N: usize> Matrix<Complex<T>, N, N> {
+    /// Determinant of complex matrix.
+    pub fn det(&self) -> Complex<T> {
+        let lu = self.lu();
+        let u = lu.u();
+        let d = (0..N).fold(Complex::one(), |d, i| d * u[(i, i)]);
+        if lu.is_odd() { -d } else { d }
+    }
+}
+
+impl<T: Float + Algebra, const R: usize, const C: usize> Matrix<Quaternion<T>, R, C> {
+    /// Complex adjoint matrix, where each quaternion `z₁ + z₂j` is replaced by the block `[[z₁, z₂], [-z̄₂, z̄₁]]`.
+    ///
+    /// The map is an injective ring homomorphism, i.e. `(A B)ᶜ = Aᶜ Bᶜ`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R2 != 2 R` or `C2 != 2 C`.
+    pub fn complex_adjoint<const R2: usize, const C2: usize>(&self) -> Matrix<Complex<T>, R2, C2> {
+        assert!(R2 == 2 * R && C2 == 2 * C, "complex adjoint must have twice as many rows and columns");
+        Matrix::from_fn(|i, j| {
+            let q = self[(i / 2, j / 2)];
+            let (z1, z2) = (q.re(), q.im());
+            match (i % 2, j % 2) {
+                (0, 0) => z1,
+                (0, _) => z2,
+                (_, 0) => -z2.conj(),
+                _ => z1.conj(),
+            }
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Index, IndexMut};
+use num_traits::{Float, Zero, One};
+use crate::*;
+use super::{*, decompose::{lu_in_place, lu_solve_in_place, qr_in_place}};
+
+
+/// Matrix which size is known only at runtime, elements are stored row by row in a heap-allocated vector.
+///
+/// Provides the same LU and QR decompositions as the fixed-size [`Matrix`], eigen-decompositions are available only for the latter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynMatrix<U> {
+    rows: usize,
+    cols: usize,
+    data: Vec<U>,
+}
+
+/// LU decomposition with partial pivoting of a [`DynMatrix`], see [`Lu`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynLu<U> {
+    lu: DynMatrix<U>,
+    perm: Vec<usize>,
+    odd: bool,
+}
+
+/// QR decomposition of a [`DynMatrix`], see [`Qr`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynQr<U> {
+    pub q: DynMatrix<U>,
+    pub r: DynMatrix<U>,
+}
+
+impl<U> DynMatrix<U> {
+    /// Create from the elements stored row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data.len() != rows * cols`.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<U>) -> Self {
+        assert_eq!(data.len(), rows * cols, "number of elements doesn't match the matrix size");
+        Self { rows, cols, data }
+    }
+    /// Create by calling `f(i, j)` for each element.
+    pub fn from_fn<F: FnMut(usize, usize) -> U>(rows: usize, cols: usize, mut f: F) -> Self {
+        Self::from_vec(rows, cols, (0..rows * cols).map(|k| f(k / cols, k % cols)).collect())
+    }
+    /// Number of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+    /// Elements stored row by row.
+    pub fn as_slice(&self) -> &[U] {
+        &self.data
+    }
+    /// Take the elements stored row by row.
+    pub fn into_vec(self) -> Vec<U> {
+        self.data
+    }
+}
+
+impl<U> Index<(usize, usize)> for DynMatrix<U> {
+    type Output = U;
+    fn index(&self, (i, j): (usize, usize)) -> &U {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+impl<U> IndexMut<(usize, usize)> for DynMatrix<U> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut U {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<U, const R: usize, const C: usize> From<Matrix<U, R, C>> for DynMatrix<U> {
+    fn from(m: Matrix<U, R, C>) -> Self {
+        let rows: [[U; C]; R] = m.into();
+        Self::from_vec(R, C, IntoIterator::into_iter(rows).flatten().collect())
+    }
+}
+impl<U: Clone> DynMatrix<U> {
+    /// Convert to the fixed-size matrix, `None` if the size doesn't match.
+    pub fn to_matrix<const R: usize, const C: usize>(&self) -> Option<Matrix<U, R, C>> {
+        if self.shape() == (R, C) { Some(Matrix::from_fn(|i, j| self[(i, j)].clone())) } else { None }
+    }
+    /// Transposed matrix.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+    }
+    /// Conjugate-transposed (Hermitian adjoint) matrix.
+    pub fn conj_transpose(&self) -> Self where U: Conj {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone().conj())
+    }
+}
+
+impl<U: Zero + One> DynMatrix<U> {
+    /// Identity matrix of size `n`.
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { U::one() } else { U::zero() })
+    }
+}
+
+impl<U> DynMatrix<U> where U: Zero + Add<Output=U> + Mul<Output=U> + Clone {
+    /// Left action on a column vector, `A v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `v` is not equal to the number of columns.
+    pub fn mul_vec(&self, v: &[U]) -> Vec<U> {
+        assert_eq!(v.len(), self.cols, "vector length doesn't match the number of columns");
+        (0..self.rows).map(|i| (0..self.cols).fold(U::zero(), |s, k| s + self[(i, k)].clone() * v[k].clone())).collect()
+    }
+}
+/// # Panics
+///
+/// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+impl<U> Mul for DynMatrix<U> where U: Zero + Add<Output=U> + Mul<Output=U> + Clone {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        assert_eq!(self.cols, other.rows, "matrix sizes don't match");
+        Self::from_fn(self.rows, other.cols, |i, j| (0..self.cols).fold(U::zero(), |s, k| s + self[(i, k)].clone() * other[(k, j)].clone()))
+    }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone> DynMatrix<Construct<T, V>> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// LU decomposition with partial pivoting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn lu(&self) -> DynLu<Construct<T, V>> {
+        assert_eq!(self.rows, self.cols, "matrix must be square");
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..self.rows).collect();
+        let odd = lu_in_place(&mut lu.data, self.rows, &mut perm);
+        DynLu { lu, perm, odd }
+    }
+    /// Inverse matrix, `None` if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.rows;
+        let lu = self.lu();
+        let mut columns = Vec::with_capacity(n);
+        for j in 0..n {
+            let e: Vec<_> = (0..n).map(|i| if i == j { Construct::one() } else { Construct::zero() }).collect();
+            columns.push(lu.solve(&e)?);
+        }
+        Some(Self::from_fn(n, n, |i, j| columns[j][i].clone()))
+    }
+    /// QR decomposition using Householder reflections.
+    pub fn qr(&self) -> DynQr<Construct<T, V>> {
+        let mut r = self.clone();
+        let mut q = Self::identity(self.rows);
+        qr_in_place(&mut r.data, self.rows, self.cols, &mut q.data);
+        DynQr { q, r }
+    }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone> DynLu<Construct<T, V>> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// Unit lower triangular factor.
+    pub fn l(&self) -> DynMatrix<Construct<T, V>> {
+        let n = self.perm.len();
+        DynMatrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            core::cmp::Ordering::Greater => self.lu[(i, j)].clone(),
+            core::cmp::Ordering::Equal => Construct::one(),
+            core::cmp::Ordering::Less => Construct::zero(),
+        })
+    }
+    /// Upper triangular factor.
+    pub fn u(&self) -> DynMatrix<Construct<T, V>> {
+        let n = self.perm.len();
+        DynMatrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)].clone() } else { Construct::zero() })
+    }
+    /// Row permutation: `i`-th row of `P A` is `perm[i]`-th row of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+    /// Whether the number of row swaps is odd.
+    pub fn is_odd(&self) -> bool {
+        self.odd
+    }
+    /// Whether the decomposed matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        (0..self.perm.len()).any(|i| self.lu[(i, i)].clone().norm_sqr().is_zero())
+    }
+    /// Solve `A x = b`, `None` if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` doesn't match the size of the matrix.
+    pub fn solve(&self, b: &[Construct<T, V>]) -> Option<Vec<Construct<T, V>>> {
+        let n = self.perm.len();
+        assert_eq!(b.len(), n, "vector length doesn't match the matrix size");
+        if self.is_singular() {
+            return None;
+        }
+        let mut x: Vec<_> = self.perm.iter().map(|&i| b[i].clone()).collect();
+        lu_solve_in_place(self.lu.as_slice(), n, &mut x);
+        Some(x)
+    }
+}
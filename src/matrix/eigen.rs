@@ -0,0 +1,246 @@
+use core::array;
+use num_traits::{Float, Zero, One};
+use crate::*;
+use super::*;
+
+
+/// Maximal number of Jacobi sweeps.
+const MAX_SWEEPS: usize = 64;
+
+/// Eigen-decomposition of a Hermitian matrix `A = V diag(values) V*`.
+///
+/// Eigenvalues are real and sorted in ascending order, `V` is unitary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HermitianEigen<T, U, const N: usize> {
+    pub values: [T; N],
+    pub vectors: Matrix<U, N, N>,
+}
+
+/// Singular value decomposition `A = U diag(values) V*`.
+///
+/// Singular values are sorted in descending order, `U` has orthonormal columns and `V` is unitary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Svd<T, U, const R: usize, const C: usize> {
+    pub u: Matrix<U, R, C>,
+    pub values: [T; C],
+    pub v: Matrix<U, C, C>,
+}
+
+/// Unitary transformation `J` of the `(p, q)` plane that diagonalizes the Hermitian matrix `[[α, γ], [γ̄, β]]` as `J* A J`.
+///
+/// Returned as `[J_pp, J_pq, J_qp, J_qq]`.
+fn jacobi<T: Float + Algebra>(alpha: T, beta: T, gamma: Complex<T>) -> [Complex<T>; 4] {
+    let r = gamma.norm();
+    // Phase `diag(1, ū)` makes the off-diagonal element real, then a real rotation is applied.
+    let u = gamma.conj() / r;
+    let theta = (r + r).atan2(alpha - beta) / (T::one() + T::one());
+    let (s, c) = (theta.sin(), theta.cos());
+    [Complex::one() * c, -Complex::one() * s, u * s, u * c]
+}
+
+/// `A <- A J` for columns `p` and `q`.
+fn rotate_columns<T: Float + Algebra, const R: usize, const C: usize>(a: &mut Matrix<Complex<T>, R, C>, p: usize, q: usize, j: [Complex<T>; 4]) {
+    for i in 0..R {
+        let (x, y) = (a[(i, p)], a[(i, q)]);
+        a[(i, p)] = x * j[0] + y * j[2];
+        a[(i, q)] = x * j[1] + y * j[3];
+    }
+}
+/// `A <- J* A` for rows `p` and `q`.
+fn rotate_rows<T: Float + Algebra, const R: usize, const C: usize>(a: &mut Matrix<Complex<T>, R, C>, p: usize, q: usize, j: [Complex<T>; 4]) {
+    for k in 0..C {
+        let (x, y) = (a[(p, k)], a[(q, k)]);
+        a[(p, k)] = j[0].conj() * x + j[2].conj() * y;
+        a[(q, k)] = j[1].conj() * x + j[3].conj() * y;
+    }
+}
+
+/// Permutation that sorts `values` by `less`.
+fn sorting<T: Copy, F: Fn(T, T) -> bool, const N: usize>(values: &[T; N], less: F) -> [usize; N] {
+    let mut idx: [usize; N] = array::from_fn(|i| i);
+    for i in 1..N {
+        let mut k = i;
+        while k > 0 && less(values[idx[k]], values[idx[k - 1]]) {
+            idx.swap(k, k - 1);
+            k -= 1;
+        }
+    }
+    idx
+}
+
+impl<T: Float + Algebra, const N: usize> Matrix<Complex<T>, N, N> {
+    /// Eigen-decomposition of a Hermitian matrix using cyclic Jacobi rotations.
+    ///
+    /// Only the upper triangle is expected to be consistent with the lower one, the matrix is not checked to be Hermitian.
+    pub fn hermitian_eigen(&self) -> HermitianEigen<T, Complex<T>, N> {
+        let mut a = *self;
+        let mut v = Self::identity();
+        let total = a.rows().iter().flatten().fold(T::zero(), |s, x| s + x.norm_sqr());
+        for _ in 0..MAX_SWEEPS {
+            let off = (0..N).flat_map(|p| (p + 1..N).map(move |q| (p, q))).fold(T::zero(), |s, (p, q)| s + a[(p, q)].norm_sqr());
+            if off <= T::epsilon() * T::epsilon() * total {
+                break;
+            }
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[(p, q)].is_zero() {
+                        continue;
+                    }
+                    let j = jacobi(a[(p, p)].re(), a[(q, q)].re(), a[(p, q)]);
+                    rotate_columns(&mut a, p, q, j);
+                    rotate_rows(&mut a, p, q, j);
+                    rotate_columns(&mut v, p, q, j);
+                }
+            }
+        }
+        let values: [T; N] = array::from_fn(|i| a[(i, i)].re());
+        let idx = sorting(&values, |x, y| x < y);
+        HermitianEigen {
+            values: idx.map(|i| values[i]),
+            vectors: Matrix::from_fn(|i, j| v[(i, idx[j])]),
+        }
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>, const N: usize> Matrix<Quaternion<T>, N, N> {
+    /// Eigen-decomposition of a quaternion Hermitian matrix, computed through its complex adjoint matrix.
+    ///
+    /// Each eigenvalue of the quaternion matrix is a double eigenvalue of the complex adjoint one.
+    /// Quaternion eigenvectors are recovered from the complex ones and orthonormalized in the quaternion sense.
+    /// For each of the eigenvalues the candidate that remains the largest after the orthogonalization is taken
+    /// among all the complex eigenvectors of that (possibly repeated) eigenvalue.
+    ///
+    /// `M` is the size of the complex adjoint matrix,
+    /// matrices up to 8x8 also have `hermitian_eigen` which doesn't need it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M != 2 N`.
+    pub fn hermitian_eigen_adjoint<const M: usize>(&self) -> HermitianEigen<T, Quaternion<T>, N> {
+        let c: Matrix<Complex<T>, M, M> = self.complex_adjoint();
+        let eig = c.hermitian_eigen();
+        // Eigenvalues closer than `tol` are considered equal.
+        let scale = eig.values.iter().fold(T::zero(), |s, x| s.max(x.abs()));
+        let tol = T::epsilon().sqrt() * scale;
+        let mut values = [T::zero(); N];
+        let mut vectors = [[Quaternion::zero(); N]; N];
+        for n in 0..N {
+            // Eigenvalues are sorted, so the `n`-th quaternion one is the `2n`-th complex one.
+            let lambda = eig.values[2 * n];
+            let (k, x, norm) = (0..M).filter(|&k| (eig.values[k] - lambda).abs() <= tol).map(|k| {
+                // First column of the complex adjoint of a quaternion vector `x` is `[z₁, -z̄₂, ...]` for each `xᵢ = z₁ + z₂j`.
+                let mut x: [Quaternion<T>; N] = array::from_fn(|i| {
+                    Quaternion::new(eig.vectors[(2 * i, k)], -eig.vectors[(2 * i + 1, k)].conj())
+                });
+                // Remove the components along the previously found vectors, `x <- x - y (y* x)`.
+                for y in vectors[..n].iter() {
+                    let p = (0..N).fold(Quaternion::zero(), |s, i| s + y[i].conj() * x[i]);
+                    for i in 0..N {
+                        x[i] = x[i] - y[i] * p;
+                    }
+                }
+                (k, x, x.iter().fold(T::zero(), |s, q| s + q.norm_sqr()))
+            }).fold(None, |best: Option<(usize, [Quaternion<T>; N], T)>, c| match best {
+                Some(b) if b.2 >= c.2 => Some(b),
+                _ => Some(c),
+            }).unwrap();
+            let norm = norm.sqrt();
+            vectors[n] = x.map(|q| q / norm);
+            values[n] = eig.values[k];
+        }
+        HermitianEigen {
+            values,
+            vectors: Matrix::from_fn(|i, j| vectors[j][i]),
+        }
+    }
+}
+
+/// Stable const generics can't express `2 N`, so the size of the complex adjoint matrix is fixed for each `N`.
+macro_rules! impl_quaternion_eigen { ($($N:literal => $M:literal),* $(,)?) => ($(
+    impl<T: Float + Algebra + Dot<Output=T>> Matrix<Quaternion<T>, $N, $N> {
+        /// Eigen-decomposition of a quaternion Hermitian matrix, see [`hermitian_eigen_adjoint`](Self::hermitian_eigen_adjoint).
+        pub fn hermitian_eigen(&self) -> HermitianEigen<T, Quaternion<T>, $N> {
+            self.hermitian_eigen_adjoint::<$M>()
+        }
+    }
+)*) }
+
+impl_quaternion_eigen!(1 => 2, 2 => 4, 3 => 6, 4 => 8, 5 => 10, 6 => 12, 7 => 14, 8 => 16);
+
+impl<T: Float + Algebra, const R: usize, const C: usize> Matrix<Complex<T>, R, C> {
+    /// Singular value decomposition using one-sided Jacobi rotations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix has less rows than columns, decompose the conjugate-transposed matrix instead.
+    pub fn svd(&self) -> Svd<T, Complex<T>, R, C> {
+        assert!(R >= C, "matrix must have at least as many rows as columns");
+        let mut a = *self;
+        let mut v = Matrix::<Complex<T>, C, C>::identity();
+        let dot = |a: &Self, p: usize, q: usize| (0..R).fold(Complex::<T>::zero(), |s, i| s + a[(i, p)].conj() * a[(i, q)]);
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..C {
+                for q in p + 1..C {
+                    let (alpha, beta, gamma) = (dot(&a, p, p).re(), dot(&a, q, q).re(), dot(&a, p, q));
+                    if gamma.norm() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let j = jacobi(alpha, beta, gamma);
+                    rotate_columns(&mut a, p, q, j);
+                    rotate_columns(&mut v, p, q, j);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let values: [T; C] = array::from_fn(|j| dot(&a, j, j).re().sqrt());
+        let idx = sorting(&values, |x, y| x > y);
+        let values = idx.map(|i| values[i]);
+        // Singular values that are negligible relative to the largest one are treated as zero.
+        let tol = T::epsilon() * T::from(R).unwrap() * values.first().cloned().unwrap_or_else(T::zero);
+        let mut u = [[Complex::zero(); R]; C];
+        for j in 0..C {
+            u[j] = if values[j] > tol {
+                array::from_fn(|i| a[(i, idx[j])] / values[j])
+            } else {
+                complement(&u[..j])
+            };
+        }
+        Svd {
+            u: Matrix::from_fn(|i, j| u[j][i]),
+            values,
+            v: Matrix::from_fn(|i, j| v[(i, idx[j])]),
+        }
+    }
+}
+
+/// Unit vector orthogonal to the orthonormal `columns`, which must be fewer than `R`.
+///
+/// Standard basis vectors are orthogonalized by the Gram–Schmidt process and the one with the largest remainder is taken.
+fn complement<T: Float + Algebra, const R: usize>(columns: &[[Complex<T>; R]]) -> [Complex<T>; R] {
+    let project_out = |x: &mut [Complex<T>; R]| {
+        for y in columns {
+            let p = y.iter().zip(x.iter()).fold(Complex::zero(), |s, (&a, &b)| s + a.conj() * b);
+            for (xi, &yi) in x.iter_mut().zip(y.iter()) {
+                *xi = *xi - yi * p;
+            }
+        }
+    };
+    let norm = |x: &[Complex<T>; R]| x.iter().fold(T::zero(), |s, z| s + z.norm_sqr());
+    let (mut x, _) = (0..R).map(|k| {
+        let mut x = array::from_fn(|i| if i == k { Complex::one() } else { Complex::zero() });
+        project_out(&mut x);
+        let n = norm(&x);
+        (x, n)
+    }).fold(None, |best: Option<([Complex<T>; R], T)>, c| match best {
+        Some(b) if b.1 >= c.1 => Some(b),
+        _ => Some(c),
+    }).unwrap();
+    // Second pass improves the orthogonality lost to rounding.
+    project_out(&mut x);
+    let n = norm(&x).sqrt();
+    x.map(|z| z / n)
+}
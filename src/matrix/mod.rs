@@ -0,0 +1,25 @@
+//! Matrices over complex and hypercomplex numbers.
+
+mod base;
+pub use base::*;
+
+mod det;
+
+mod decompose;
+pub use decompose::*;
+
+mod eigen;
+pub use eigen::*;
+
+#[cfg(feature = "alloc")]
+mod dynamic;
+#[cfg(feature = "alloc")]
+pub use dynamic::*;
+
+#[cfg(feature = "random")]
+mod random;
+#[cfg(feature = "random")]
+pub use random::*;
+
+#[cfg(all(test, feature = "random", feature = "approx"))]
+mod tests;
@@ -0,0 +1,12 @@
+use rand::Rng;
+use rand_distr::Distribution;
+use super::*;
+
+
+pub use rand_distr::StandardNormal;
+
+impl<U, const R: usize, const C: usize> Distribution<Matrix<U, R, C>> for StandardNormal where StandardNormal: Distribution<U> {
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Matrix<U, R, C> {
+        Matrix::from_fn(|_, _| rng.sample(Self))
+    }
+}
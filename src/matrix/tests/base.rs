@@ -0,0 +1,129 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, matrix::*, transform::*, Complex, Quaternion};
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+type Mq<const N: usize> = Matrix<Quaternion<f64>, N, N>;
+
+pub fn assert_matrix_eq<U: AbsDiffEq<Epsilon=f64> + core::fmt::Debug, const R: usize, const C: usize>(a: &Matrix<U, R, C>, b: &Matrix<U, R, C>, epsilon: f64) {
+    for (ra, rb) in a.rows().iter().zip(b.rows().iter()) {
+        assert_abs_diff_eq!(&ra[..], &rb[..], epsilon=epsilon);
+    }
+}
+
+#[test]
+fn mul_order() {
+    let (i, j, k) = (Quaternion::<f64>::i(), Quaternion::j(), Quaternion::k());
+    let a = Matrix::new([[i]]);
+    let b = Matrix::new([[j]]);
+    assert_eq!((a * b)[(0, 0)], k);
+    assert_eq!((b * a)[(0, 0)], -k);
+    assert_eq!(a.scale_left(j)[(0, 0)], -k);
+    assert_eq!(a.scale_right(j)[(0, 0)], k);
+}
+
+#[test]
+fn mul_associativity() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA70);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Matrix<Quaternion<f64>, 2, 3> = rng.sample(StandardNormal);
+        let b: Matrix<Quaternion<f64>, 3, 4> = rng.sample(StandardNormal);
+        let c: Matrix<Quaternion<f64>, 4, 2> = rng.sample(StandardNormal);
+        assert_matrix_eq(&((a * b) * c), &(a * (b * c)), 1e-10);
+        let v: [Quaternion<f64>; 4] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        assert_abs_diff_eq!(&a.mul_vec(b.mul_vec(v))[..], &(a * b).mul_vec(v)[..], epsilon=1e-10);
+        let w: [Quaternion<f64>; 2] = [rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        assert_abs_diff_eq!(&b.vec_mul(a.vec_mul(w))[..], &(a * b).vec_mul(w)[..], epsilon=1e-10);
+    }
+}
+
+#[test]
+fn conj_transpose() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA71);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Matrix<Quaternion<f64>, 2, 3> = rng.sample(StandardNormal);
+        let b: Matrix<Quaternion<f64>, 3, 2> = rng.sample(StandardNormal);
+        assert_matrix_eq(&(a * b).conj_transpose(), &(b.conj_transpose() * a.conj_transpose()), 1e-10);
+        assert_eq!(a.transpose().transpose(), a);
+    }
+}
+
+#[test]
+fn trace() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA72);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Matrix<Complex<f64>, 3, 3> = rng.sample(StandardNormal);
+        let b: Matrix<Complex<f64>, 3, 3> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b).trace(), (b * a).trace(), epsilon=1e-10);
+        // Only the real part of the trace is cyclic for quaternions.
+        let a: Mq<3> = rng.sample(StandardNormal);
+        let b: Mq<3> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b).trace().w(), (b * a).trace().w(), epsilon=1e-10);
+    }
+}
+
+#[test]
+fn inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA73);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<4> = rng.sample(StandardNormal);
+        let b = a.inverse().unwrap();
+        assert_matrix_eq(&(a * b), &Mq::<4>::identity(), 1e-8);
+        assert_matrix_eq(&(b * a), &Mq::<4>::identity(), 1e-8);
+    }
+    assert!(Mq::<2>::new([[Quaternion::zero(); 2]; 2]).inverse().is_none());
+}
+
+#[test]
+fn det() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA74);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Matrix<Complex<f64>, 3, 3> = rng.sample(StandardNormal);
+        let b: Matrix<Complex<f64>, 3, 3> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!((a * b).det(), a.det() * b.det(), epsilon=1e-8);
+        assert_abs_diff_eq!(a.dieudonne_det(), a.det().norm(), epsilon=1e-10);
+        let m = Matrix::new([[a[(0, 0)], a[(0, 1)]], [a[(1, 0)], a[(1, 1)]]]);
+        assert_abs_diff_eq!(m.det(), a[(0, 0)] * a[(1, 1)] - a[(0, 1)] * a[(1, 0)], epsilon=1e-12);
+    }
+}
+
+#[test]
+fn study_det() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA75);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<3> = rng.sample(StandardNormal);
+        let b: Mq<3> = rng.sample(StandardNormal);
+        let (da, db) = (a.study_det(), b.study_det());
+        assert_abs_diff_eq!((a * b).study_det(), da * db, epsilon=1e-8 * da * db);
+        let c: Matrix<Complex<f64>, 6, 6> = a.complex_adjoint();
+        assert_abs_diff_eq!(c.det(), Complex::new(da, 0.0), epsilon=1e-8 * da);
+    }
+}
+
+#[test]
+fn complex_adjoint() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA76);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Matrix<Quaternion<f64>, 2, 3> = rng.sample(StandardNormal);
+        let b: Matrix<Quaternion<f64>, 3, 2> = rng.sample(StandardNormal);
+        let ab: Matrix<Complex<f64>, 4, 4> = (a * b).complex_adjoint();
+        let (ac, bc): (Matrix<Complex<f64>, 4, 6>, Matrix<Complex<f64>, 6, 4>) = (a.complex_adjoint(), b.complex_adjoint());
+        assert_matrix_eq(&ab, &(ac * bc), 1e-10);
+    }
+}
+
+#[test]
+fn moebius() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA77);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Quaternion<f64>> = rng.sample(StandardNormal);
+        let b: Moebius<Quaternion<f64>> = rng.sample(StandardNormal);
+        let (ma, mb): (Mq<2>, Mq<2>) = (a.into(), b.into());
+        let c: Moebius<Quaternion<f64>> = (ma * mb).into();
+        assert_eq!(c, a.chain(b));
+        let ai: Moebius<Quaternion<f64>> = ma.inverse().unwrap().into();
+        assert!(ai.projective_eq(&a.inverse(), 1e-8));
+    }
+}
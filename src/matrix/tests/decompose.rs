@@ -0,0 +1,167 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, matrix::*, Complex, Quaternion};
+use super::base::assert_matrix_eq;
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+type Mc<const R: usize, const C: usize> = Matrix<Complex<f64>, R, C>;
+type Mq<const R: usize, const C: usize> = Matrix<Quaternion<f64>, R, C>;
+
+fn is_upper<U: NormSqr<Output=f64> + Clone, const R: usize, const C: usize>(a: &Matrix<U, R, C>, epsilon: f64) -> bool {
+    (0..R).all(|i| (0..C.min(i)).all(|j| a[(i, j)].clone().norm_sqr() <= epsilon * epsilon))
+}
+fn diag<U: Zero + One + core::ops::Mul<f64, Output=U>, const N: usize>(values: [f64; N]) -> Matrix<U, N, N> {
+    Matrix::from_fn(|i, j| if i == j { U::one() * values[i] } else { U::zero() })
+}
+
+#[test]
+fn lu() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA80);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<4, 4> = rng.sample(StandardNormal);
+        let lu = a.lu();
+        let (l, u, p) = (lu.l(), lu.u(), lu.permutation());
+        assert!(is_upper(&u, 0.0));
+        assert!(is_upper(&l.transpose(), 0.0));
+        let pa = Matrix::from_fn(|i, j| a[(p[i], j)]);
+        assert_matrix_eq(&pa, &(l * u), 1e-10);
+
+        let x: [Quaternion<f64>; 4] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        let y = lu.solve(a.mul_vec(x)).unwrap();
+        assert_abs_diff_eq!(&x[..], &y[..], epsilon=1e-8);
+    }
+    let s = Mq::<2, 2>::new([[Quaternion::one(), Quaternion::i()], [Quaternion::j(), Quaternion::<f64>::j() * Quaternion::i()]]);
+    assert!(s.lu().is_singular());
+    assert!(s.lu().solve([Quaternion::one(); 2]).is_none());
+}
+
+#[test]
+fn lu_parity() {
+    let a = Mc::<3, 3>::new([
+        [Complex::zero(), Complex::one(), Complex::zero()],
+        [Complex::one(), Complex::zero(), Complex::zero()],
+        [Complex::zero(), Complex::zero(), Complex::one()],
+    ]);
+    assert!(a.lu().is_odd());
+    assert_abs_diff_eq!(a.det(), -Complex::one(), epsilon=1e-12);
+}
+
+#[test]
+fn qr_complex() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA81);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mc<4, 3> = rng.sample(StandardNormal);
+        let Qr { q, r } = a.qr();
+        assert_matrix_eq(&(q.conj_transpose() * q), &Mc::<4, 4>::identity(), 1e-10);
+        assert!(is_upper(&r, 1e-10));
+        assert_matrix_eq(&(q * r), &a, 1e-10);
+    }
+}
+
+#[test]
+fn qr_quaternion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA82);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<3, 4> = rng.sample(StandardNormal);
+        let Qr { q, r } = a.qr();
+        assert_matrix_eq(&(q.conj_transpose() * q), &Mq::<3, 3>::identity(), 1e-10);
+        assert!(is_upper(&r, 1e-10));
+        assert_matrix_eq(&(q * r), &a, 1e-10);
+    }
+}
+
+#[test]
+fn hermitian_eigen_complex() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA83);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let b: Mc<4, 4> = rng.sample(StandardNormal);
+        let a = b + b.conj_transpose();
+        let HermitianEigen { values, vectors: v } = a.hermitian_eigen();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        assert_matrix_eq(&(v.conj_transpose() * v), &Mc::<4, 4>::identity(), 1e-10);
+        assert_matrix_eq(&(v * diag(values) * v.conj_transpose()), &a, 1e-10);
+    }
+}
+
+#[test]
+fn hermitian_eigen_quaternion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA84);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let b: Mq<3, 3> = rng.sample(StandardNormal);
+        let a = b + b.conj_transpose();
+        let HermitianEigen { values, vectors: v } = a.hermitian_eigen();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        assert_matrix_eq(&(v.conj_transpose() * v), &Mq::<3, 3>::identity(), 1e-10);
+        assert_matrix_eq(&(v * diag(values) * v.conj_transpose()), &a, 1e-10);
+    }
+}
+
+#[test]
+fn svd() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA85);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mc<5, 3> = rng.sample(StandardNormal);
+        let Svd { u, values, v } = a.svd();
+        assert!(values.windows(2).all(|w| w[0] >= w[1]));
+        assert_matrix_eq(&(u.conj_transpose() * u), &Mc::<3, 3>::identity(), 1e-10);
+        assert_matrix_eq(&(v.conj_transpose() * v), &Mc::<3, 3>::identity(), 1e-10);
+        assert_matrix_eq(&(u * diag(values) * v.conj_transpose()), &a, 1e-10);
+    }
+}
+
+#[test]
+fn hermitian_eigen_quaternion_repeated() {
+    let a = Mq::<3, 3>::identity().scale_left(Quaternion::one() * 3.0);
+    let HermitianEigen { values, vectors: v } = a.hermitian_eigen();
+    assert_abs_diff_eq!(&values[..], &[3.0; 3][..], epsilon=1e-12);
+    assert_matrix_eq(&(v.conj_transpose() * v), &Mq::<3, 3>::identity(), 1e-10);
+
+    let mut rng = XorShiftRng::seed_from_u64(0xAA86);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let b: Mq<4, 4> = rng.sample(StandardNormal);
+        let q = b.qr().q;
+        let a = q * diag([-1.0, 2.0, 2.0, 2.0]) * q.conj_transpose();
+        let HermitianEigen { values, vectors: v } = a.hermitian_eigen();
+        assert_abs_diff_eq!(&values[..], &[-1.0, 2.0, 2.0, 2.0][..], epsilon=1e-10);
+        assert_matrix_eq(&(v.conj_transpose() * v), &Mq::<4, 4>::identity(), 1e-10);
+        assert_matrix_eq(&(v * diag(values) * v.conj_transpose()), &a, 1e-10);
+    }
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA87);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        // The last column is a combination of the others, and the second one is zero.
+        let b: Mc<5, 4> = rng.sample(StandardNormal);
+        let a = Mc::<5, 4>::from_fn(|i, j| match j {
+            1 => Complex::zero(),
+            3 => b[(i, 0)] + b[(i, 2)] * 2.0,
+            _ => b[(i, j)],
+        });
+        let Svd { u, values, v } = a.svd();
+        assert!(values.windows(2).all(|w| w[0] >= w[1]));
+        assert!(values[2] <= 1e-10 && values[3] <= 1e-10);
+        assert_matrix_eq(&(u.conj_transpose() * u), &Mc::<4, 4>::identity(), 1e-10);
+        assert_matrix_eq(&(v.conj_transpose() * v), &Mc::<4, 4>::identity(), 1e-10);
+        assert_matrix_eq(&(u * diag(values) * v.conj_transpose()), &a, 1e-10);
+    }
+    let Svd { u, .. } = Mc::<3, 2>::zero().svd();
+    assert_matrix_eq(&(u.conj_transpose() * u), &Mc::<2, 2>::identity(), 1e-12);
+}
+
+#[test]
+fn hermitian_eigen_quaternion_adjoint() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA88);
+    let b: Mq<3, 3> = rng.sample(StandardNormal);
+    let a = b + b.conj_transpose();
+    assert_eq!(a.hermitian_eigen_adjoint::<6>(), a.hermitian_eigen());
+}
+
+#[test]
+#[should_panic]
+fn hermitian_eigen_quaternion_adjoint_size() {
+    Mq::<3, 3>::identity().hermitian_eigen_adjoint::<4>();
+}
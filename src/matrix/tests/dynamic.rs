@@ -0,0 +1,57 @@
+use std::vec::Vec;
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, matrix::*, Complex, Quaternion};
+use super::base::assert_matrix_eq;
+
+
+const SAMPLE_ATTEMPTS: usize = 64;
+type Mq<const R: usize, const C: usize> = Matrix<Quaternion<f64>, R, C>;
+
+#[test]
+fn conversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA90);
+    let a: Mq<2, 3> = rng.sample(StandardNormal);
+    let d = DynMatrix::from(a);
+    assert_eq!(d.shape(), (2, 3));
+    assert_eq!(d[(1, 2)], a[(1, 2)]);
+    assert_eq!(d.to_matrix::<2, 3>(), Some(a));
+    assert_eq!(d.to_matrix::<3, 2>(), None);
+    assert_eq!(d.transpose().to_matrix::<3, 2>(), Some(a.transpose()));
+}
+
+#[test]
+fn lu() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA91);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<4, 4> = rng.sample(StandardNormal);
+        let (lu, dlu) = (a.lu(), DynMatrix::from(a).lu());
+        assert_eq!(dlu.permutation(), &lu.permutation()[..]);
+        assert_eq!(dlu.is_odd(), lu.is_odd());
+        assert_eq!(dlu.l().to_matrix::<4, 4>(), Some(lu.l()));
+        assert_eq!(dlu.u().to_matrix::<4, 4>(), Some(lu.u()));
+
+        let x: Vec<Quaternion<f64>> = (0..4).map(|_| rng.sample(StandardNormal)).collect();
+        let y = dlu.solve(&DynMatrix::from(a).mul_vec(&x)).unwrap();
+        assert_abs_diff_eq!(&x[..], &y[..], epsilon=1e-8);
+
+        let inv = DynMatrix::from(a).inverse().unwrap().to_matrix::<4, 4>().unwrap();
+        assert_matrix_eq(&(a * inv), &Mq::<4, 4>::identity(), 1e-10);
+    }
+    let s = DynMatrix::from_vec(2, 2, std::vec![Complex::<f64>::one(), Complex::i(), Complex::i(), -Complex::one()]);
+    assert!(s.lu().is_singular());
+    assert!(s.inverse().is_none());
+}
+
+#[test]
+fn qr() {
+    let mut rng = XorShiftRng::seed_from_u64(0xAA92);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Mq<4, 3> = rng.sample(StandardNormal);
+        let DynQr { q, r } = DynMatrix::from(a).qr();
+        let (q, r) = (q.to_matrix::<4, 4>().unwrap(), r.to_matrix::<4, 3>().unwrap());
+        assert_matrix_eq(&(q.conj_transpose() * q), &Mq::<4, 4>::identity(), 1e-10);
+        assert_matrix_eq(&(q * r), &a, 1e-10);
+    }
+}
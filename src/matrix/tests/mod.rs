@@ -0,0 +1,4 @@
+mod base;
+mod decompose;
+#[cfg(feature = "alloc")]
+mod dynamic;
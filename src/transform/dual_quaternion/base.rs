@@ -0,0 +1,164 @@
+use core::ops::Neg;
+use num_traits::{Float, Zero, One, Inv};
+use crate::{*, transform::*};
+
+
+/// Dual quaternion `real + ε dual`, where `ε² = 0`.
+///
+/// Unit dual quaternions represent rigid-body transformations, i.e. rotations followed by translations.
+/// Point `p` is transformed as `r * p * r⁻¹ + t`, where `r` is the rotation and `t` is the translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternion<T> {
+    real: Quaternion<T>,
+    dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+
+    pub fn real_ref(&self) -> &Quaternion<T> { &self.real }
+    pub fn dual_ref(&self) -> &Quaternion<T> { &self.dual }
+
+    pub fn real_mut(&mut self) -> &mut Quaternion<T> { &mut self.real }
+    pub fn dual_mut(&mut self) -> &mut Quaternion<T> { &mut self.dual }
+}
+
+impl<T: Clone> DualQuaternion<T> {
+    pub fn real(&self) -> Quaternion<T> { self.real.clone() }
+    pub fn dual(&self) -> Quaternion<T> { self.dual.clone() }
+}
+
+/// Cross product of 3-dimensional vectors.
+fn cross<T: Float>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+/// Dot product of 3-dimensional vectors.
+fn dot<T: Float>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> DualQuaternion<T> {
+    /// Rotation by the unit quaternion `rotation` followed by the `translation`.
+    pub fn from_rotation_translation(rotation: Quaternion<T>, translation: [T; 3]) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        Self::new(rotation, Quaternion::from_vector(translation) * rotation * half)
+    }
+    /// Pure rotation by the unit quaternion.
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self::new(rotation, Quaternion::zero())
+    }
+    /// Pure translation.
+    pub fn from_translation(translation: [T; 3]) -> Self {
+        Self::from_rotation_translation(Quaternion::one(), translation)
+    }
+
+    /// Get rotation part. The dual quaternion is expected to be normalized.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+    /// Get translation part. The dual quaternion is expected to be normalized.
+    pub fn translation(&self) -> [T; 3] {
+        let two = T::one() + T::one();
+        (self.dual * self.real.conj() * two).vector()
+    }
+    /// Split into rotation and translation. The dual quaternion is expected to be normalized.
+    pub fn to_rotation_translation(&self) -> (Quaternion<T>, [T; 3]) {
+        (self.rotation(), self.translation())
+    }
+
+    /// Quaternion conjugate of both parts, which is the inverse for normalized dual quaternions.
+    pub fn conj(self) -> Self {
+        Self::new(self.real.conj(), self.dual.conj())
+    }
+
+    /// Make the dual quaternion unit, i.e. `|real| = 1` and `real · dual = 0`.
+    pub fn normalize(self) -> Self {
+        let n = self.real.norm();
+        let (real, dual) = (self.real / n, self.dual / n);
+        Self::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Raise normalized dual quaternion to the real power `t` along its screw motion.
+    pub fn powf(self, t: T) -> Self {
+        let two = T::one() + T::one();
+        let v = self.real.vector();
+        let vn = dot(v, v).sqrt();
+        let trans = self.translation();
+        if vn <= T::epsilon() {
+            // Pure translation.
+            let [x, y, z] = trans;
+            return Self::from_translation([x * t, y * t, z * t]);
+        }
+        // Screw parameters: axis `l`, moment `m`, half of rotation angle and displacement along the axis.
+        let l = [v[0] / vn, v[1] / vn, v[2] / vn];
+        let half = vn.atan2(self.real.w());
+        let disp = dot(trans, l);
+        let cot = half.cos() / half.sin();
+        let tl = cross(trans, l);
+        let m = [0, 1, 2].map(|i| (tl[i] + (trans[i] - disp * l[i]) * cot) / two);
+
+        let (half, disp) = (half * t, disp * t);
+        let (sin, cos) = (half.sin(), half.cos());
+        let real = Quaternion::from_vector(l) * sin + cos;
+        let dual = (Quaternion::from_vector(m) * sin + Quaternion::from_vector(l) * (disp / two * cos)) - disp / two * sin;
+        Self::new(real, dual)
+    }
+
+    /// Screw linear interpolation between normalized dual quaternions along the shortest path.
+    ///
+    /// Returns `a` for `t == 0` and `±b` for `t == 1`, both rotation and translation change with constant velocity.
+    pub fn sclerp(a: Self, b: Self, t: T) -> Self {
+        let b = if a.real.dot(b.real) < T::zero() { -b } else { b };
+        a.chain(a.conj().chain(b).powf(t))
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Neg for DualQuaternion<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.dual)
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Inv for DualQuaternion<T> {
+    type Output = Self;
+    fn inv(self) -> Self {
+        let ri = self.real.inv();
+        Self::new(ri, -ri * self.dual * ri)
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Inverse for DualQuaternion<T> {
+    fn inverse(self) -> Self {
+        self.inv()
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Identity for DualQuaternion<T> {
+    fn identity() -> Self {
+        Self::new(Quaternion::one(), Quaternion::zero())
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Chain<[T; 3]> for DualQuaternion<T> {
+    fn chain(self, other: Self) -> Self {
+        Self::new(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real,
+        )
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Transform<[T; 3]> for DualQuaternion<T> {
+    fn apply(&self, x: [T; 3]) -> [T; 3] {
+        let [a, b, c] = self.real.rotate_vector(x);
+        let [p, q, r] = self.translation();
+        [a + p, b + q, c + r]
+    }
+}
@@ -0,0 +1,5 @@
+mod base;
+pub use base::*;
+
+#[cfg(all(test, feature = "random", feature = "approx"))]
+mod tests;
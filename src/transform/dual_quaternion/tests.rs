@@ -0,0 +1,124 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, transform::*, Quaternion, random::{Unit, StandardNormal}, interp::slerp};
+
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+type Dq = DualQuaternion<f64>;
+
+fn sample(rng: &mut XorShiftRng) -> Dq {
+    let r: Quaternion<f64> = rng.sample(Unit);
+    Dq::from_rotation_translation(r, sample_point(rng))
+}
+fn sample_point(rng: &mut XorShiftRng) -> [f64; 3] {
+    [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)]
+}
+
+fn assert_same(a: Dq, b: Dq) {
+    let b = if a.real().dot(b.real()) < 0.0 { -b } else { b };
+    assert_abs_diff_eq!(a.real(), b.real(), epsilon=1e-10);
+    assert_abs_diff_eq!(a.dual(), b.dual(), epsilon=1e-10);
+}
+
+#[test]
+fn rotation_translation() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A0);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let r: Quaternion<f64> = rng.sample(Unit);
+        let t = sample_point(&mut rng);
+        let a = Dq::from_rotation_translation(r, t);
+        assert_abs_diff_eq!(a.rotation(), r, epsilon=1e-12);
+        assert_abs_diff_eq!(&a.translation()[..], &t[..], epsilon=1e-12);
+        for _ in 0..POINT_ATTEMPTS {
+            let x = sample_point(&mut rng);
+            let y = r.rotate_vector(x);
+            let y = [y[0] + t[0], y[1] + t[1], y[2] + t[2]];
+            assert_abs_diff_eq!(&a.apply(x)[..], &y[..], epsilon=1e-12);
+        }
+    }
+}
+
+#[test]
+fn chain() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A1);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a = sample(&mut rng);
+        let b = sample(&mut rng);
+        let c = a.chain(b);
+        for _ in 0..POINT_ATTEMPTS {
+            let x = sample_point(&mut rng);
+            assert_abs_diff_eq!(&c.apply(x)[..], &a.apply(b.apply(x))[..], epsilon=1e-12);
+        }
+    }
+}
+
+#[test]
+fn identity() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A2);
+    let e = Dq::identity();
+    for _ in 0..POINT_ATTEMPTS {
+        let x = sample_point(&mut rng);
+        assert_abs_diff_eq!(&e.apply(x)[..], &x[..], epsilon=1e-15);
+    }
+}
+
+#[test]
+fn inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A3);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a = sample(&mut rng);
+        assert_same(a.inv(), a.conj());
+        assert_same(a.chain(a.inv()), Dq::identity());
+        for _ in 0..POINT_ATTEMPTS {
+            let x = sample_point(&mut rng);
+            assert_abs_diff_eq!(&a.inv().apply(a.apply(x))[..], &x[..], epsilon=1e-12);
+        }
+    }
+}
+
+#[test]
+fn normalize() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A4);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a = sample(&mut rng);
+        let b = Dq::new(a.real() * 3.0, a.dual() * 3.0 + a.real() * 0.5);
+        assert_same(b.normalize(), a);
+    }
+}
+
+#[test]
+fn sclerp_endpoints() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A5);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a = sample(&mut rng);
+        let b = sample(&mut rng);
+        assert_same(Dq::sclerp(a, b, 0.0), a);
+        assert_same(Dq::sclerp(a, b, 1.0), b);
+    }
+}
+
+#[test]
+fn sclerp_half() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A6);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a = sample(&mut rng);
+        let b = sample(&mut rng);
+        let h = Dq::sclerp(a, b, 0.5);
+        // Two equal steps from `a` reach `b`.
+        assert_same(h.chain(a.conj().chain(h)), b);
+        // Rotation part follows slerp.
+        let r = slerp(a.real(), b.real(), 0.5);
+        let hr = if h.real().dot(r) < 0.0 { -h.real() } else { h.real() };
+        assert_abs_diff_eq!(hr, r, epsilon=1e-10);
+    }
+}
+
+#[test]
+fn sclerp_translation() {
+    let a = Dq::from_translation([1.0, 2.0, 3.0]);
+    let b = Dq::from_translation([3.0, -2.0, 5.0]);
+    let h = Dq::sclerp(a, b, 0.25);
+    assert_abs_diff_eq!(&h.translation()[..], &[1.5, 1.0, 3.5][..], epsilon=1e-12);
+}
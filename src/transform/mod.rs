@@ -4,7 +4,13 @@ pub use traits::*;
 mod moebius;
 pub use moebius::*;
 
+mod dual_quaternion;
+pub use dual_quaternion::*;
+
+mod projective;
+pub use projective::*;
+
 
 pub mod prelude {
-    pub use super::{Transform, Identity, Deriv, DerivDir, Chain};
+    pub use super::{Transform, Identity, Inverse, Deriv, DerivDir, Chain};
 }
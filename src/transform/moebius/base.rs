@@ -1,6 +1,6 @@
-use core::ops::{Neg, Add, Sub, Mul, Div};
-use num_traits::{Zero, One, NumCast};
-use crate::{*, transform::*};
+use core::ops::{Neg, Add, Sub, Mul, Div, MulAssign, DivAssign};
+use num_traits::{Float, Zero, One, NumCast};
+use crate::{*, transform::*, matrix::Matrix};
 
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -13,12 +13,26 @@ impl<U> From<[U; 4]> for Moebius<U> {
         Self { data: array }
     }
 }
+#[allow(clippy::from_over_into)]
 impl<U> Into<[U; 4]> for Moebius<U> {
     fn into(self) -> [U; 4] {
         self.data
     }
 }
 
+impl<U> From<Matrix<U, 2, 2>> for Moebius<U> {
+    fn from(m: Matrix<U, 2, 2>) -> Self {
+        let [[a, b], [c, d]]: [[U; 2]; 2] = m.into();
+        Self::new(a, b, c, d)
+    }
+}
+impl<U> From<Moebius<U>> for Matrix<U, 2, 2> {
+    fn from(moebius: Moebius<U>) -> Self {
+        let [a, b, c, d] = moebius.data;
+        Matrix::new([[a, b], [c, d]])
+    }
+}
+
 impl<U> Moebius<U> {
     pub fn new(a: U, b: U, c: U, d: U) -> Self {
         Self::from([a, b, c, d])
@@ -48,17 +62,49 @@ impl<U: Zero + One> Identity for Moebius<U> {
     }
 }
 
-impl<U> Chain<U> for Moebius<U> where U: Add<Output=U> + Mul<Output=U> + Div<Output=U> + Clone {
+/// Composition of transformations is the product of coefficient matrices, which is valid only for associative coefficients.
+impl<U> Chain<U> for Moebius<U> where U: Associative + Add<Output=U> + Mul<Output=U> + Div<Output=U> + Clone {
     fn chain(self, other: Self) -> Self {
+        let ([a, b, c, d], [e, f, g, h]) = (self.data, other.data);
         Self::new(
-            self.a()*other.a() + self.b()*other.c(),
-            self.a()*other.b() + self.b()*other.d(),
-            self.c()*other.a() + self.d()*other.c(),
-            self.c()*other.b() + self.d()*other.d(),
+            a.clone()*e.clone() + b.clone()*g.clone(),
+            a*f.clone() + b*h.clone(),
+            c.clone()*e + d.clone()*g,
+            c*f + d*h,
         )
     }
 }
 
+/// Composition of transformations on references, same as [`Chain::chain`] but without cloning coefficients.
+impl<'b, U> Mul<&'b Moebius<U>> for &Moebius<U> where U: Associative + Add<Output=U>, for<'x, 'y> &'x U: Mul<&'y U, Output=U> {
+    type Output = Moebius<U>;
+    fn mul(self, other: &'b Moebius<U>) -> Self::Output {
+        let ([a, b, c, d], [e, f, g, h]) = (&self.data, &other.data);
+        Moebius::new(a*e + b*g, a*f + b*h, c*e + d*g, c*f + d*h)
+    }
+}
+/// Composition with the inverse transformation.
+impl<'a, 'b, U> Div<&'b Moebius<U>> for &'a Moebius<U> where
+    Moebius<U>: Inverse + Clone,
+    for<'x> &'a Moebius<U>: Mul<&'x Moebius<U>, Output=Moebius<U>>,
+{
+    type Output = Moebius<U>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: &'b Moebius<U>) -> Self::Output {
+        self * &other.clone().inverse()
+    }
+}
+impl<'a, U> MulAssign<&'a Self> for Moebius<U> where for<'x> &'x Self: Mul<&'a Self, Output=Self> {
+    fn mul_assign(&mut self, other: &'a Self) {
+        *self = &*self * other;
+    }
+}
+impl<'a, U> DivAssign<&'a Self> for Moebius<U> where for<'x> &'x Self: Div<&'a Self, Output=Self> {
+    fn div_assign(&mut self, other: &'a Self) {
+        *self = &*self / other;
+    }
+}
+
 impl<U> Transform<U> for Moebius<U> where U: Add<Output=U> + Mul<Output=U> + Div<Output=U> + Clone {
     fn apply(&self, x: U) -> U {
         (self.a()*x.clone() + self.b())/(self.c()*x + self.d())
@@ -70,18 +116,56 @@ impl<T: Algebra + Clone, U: Algebra<T> + Clone> Transform<Construct<T, Construct
     }
 }
 
-impl<U: Neg<Output=U> + Mul<Output=U> + Div<Output=U> + Sub<Output=U> + Clone> Moebius<U> {
+impl<U: Commutative + Neg<Output=U> + Mul<Output=U> + Div<Output=U> + Sub<Output=U> + Clone> Moebius<U> {
+    /// Determinant `a d - b c` of the coefficient matrix.
+    ///
+    /// It is meaningful only for commutative coefficients,
+    /// use [`dieudonne_det`](Self::dieudonne_det) or [`study_det`](Self::study_det) for quaternions.
     pub fn det(&self) -> U {
         self.a()*self.d() - self.b()*self.c()
     }
+}
+
+impl<T: Float + Algebra, V: Algebra<T> + Clone> Moebius<Construct<T, V>> where Construct<T, V>: Associative + DivisionAlgebra {
+    /// Dieudonné determinant of the coefficient matrix, a non-negative real number.
+    ///
+    /// It is equal to `|det|` for complex coefficients.
+    pub fn dieudonne_det(&self) -> T {
+        Matrix::from(self.clone()).dieudonne_det()
+    }
+    /// Study determinant of the coefficient matrix, the square of [`dieudonne_det`](Self::dieudonne_det).
+    pub fn study_det(&self) -> T {
+        Matrix::from(self.clone()).study_det()
+    }
+}
+
+impl<T: Float + Algebra> Moebius<Complex<T>> {
+    /// Scale coefficients so that `det() == 1`, i.e. divide them by a square root of the determinant.
     pub fn normalize(mut self) -> Self {
-        let det = self.det();
-        self.data.iter_mut().for_each(|x| *x = x.clone() / det.clone());
+        let s = self.det().sqrt();
+        self.data.iter_mut().for_each(|x| *x /= s);
         self
     }
 }
+impl<T: Float + Algebra, U: Algebra<T> + Clone> Moebius<Construct<T, Construct<T, U>>> where Construct<T, Construct<T, U>>: Associative + DivisionAlgebra {
+    /// Scale coefficients so that `dieudonne_det() == 1`, i.e. divide them by a square root of the Dieudonné determinant.
+    ///
+    /// Coefficients are scaled by a real number, so the transformation is not changed.
+    pub fn normalize(mut self) -> Self {
+        let s = self.dieudonne_det().sqrt();
+        self.data.iter_mut().for_each(|x| *x = x.clone() / s);
+        self
+    }
+}
+
+impl<U: Add<Output=U> + Clone> Moebius<U> {
+    pub fn trace(&self) -> U {
+        self.a() + self.d()
+    }
+}
 
 impl<T: Algebra + Clone> Deriv<Complex<T>> for Moebius<Complex<T>> {
+    #[allow(clippy::needless_return)]
     fn deriv(&self, p: Complex<T>) -> Complex<T> {
         let u: Complex<T> = self.a() * p.clone() + self.b();
         let d: Complex<T> = self.c() * p + self.d();
@@ -90,6 +174,7 @@ impl<T: Algebra + Clone> Deriv<Complex<T>> for Moebius<Complex<T>> {
 }
 
 impl<T: NumCast + Algebra + Dot<Output=T> + Clone> DerivDir<Quaternion<T>> for Moebius<Complex<T>> {
+    #[allow(clippy::needless_return)]
     fn deriv_dir(&self, p: Quaternion<T>, v: Quaternion<T>) -> Quaternion<T> {
         let u = self.a() * p.clone() + self.b();
         let d = self.c() * p + self.d();
@@ -0,0 +1,18 @@
+use core::ops::{Neg, Add, Sub, Mul, Div};
+use num_traits::Zero;
+use crate::{*, batch::{ConstructSoA, StandardFlat}};
+use super::*;
+
+
+impl<T, U> Moebius<Construct<T, U>> where
+    T: Copy + Zero + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>,
+    Construct<T, U>: StandardFlat<T>,
+{
+    /// Apply the transformation to every element of the batch.
+    ///
+    /// The dimension of the batch may be greater than the dimension of the coefficients,
+    /// then the transformation is applied the same way as `Transform::apply` for elements of higher level.
+    pub fn apply_soa<const N: usize>(&self, x: &ConstructSoA<T, N>) -> ConstructSoA<T, N> {
+        x.moebius(self.a_ref().as_slice(), self.b_ref().as_slice(), self.c_ref().as_slice(), self.d_ref().as_slice())
+    }
+}
@@ -0,0 +1,137 @@
+use num_traits::{Float, Zero, One};
+use crate::{*, transform::*};
+use super::*;
+
+
+/// Conjugacy class of a Moebius transformation of complex plane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoebiusClass {
+    /// Identity transformation.
+    Identity,
+    /// Conjugate to a rotation `z -> e^(iθ) z`.
+    Elliptic,
+    /// Conjugate to a translation `z -> z + 1`, has a single fixed point.
+    Parabolic,
+    /// Conjugate to a dilation `z -> k z` with real `k > 0`.
+    Hyperbolic,
+    /// Conjugate to `z -> k z` with `|k| != 1`, where `k` is not a positive real number.
+    Loxodromic,
+}
+
+/// Point of the extended complex plane from homogeneous coordinates.
+fn point<T: Float + Algebra>(v: [Complex<T>; 2]) -> Projective<Complex<T>> {
+    let [x, y] = v;
+    if y.is_zero() { Projective::Infinity } else { Projective::Finite(x / y) }
+}
+
+impl<T: Float + Algebra> Moebius<Complex<T>> {
+    /// Square of the trace of the normalized transformation, `trace² / det`.
+    pub fn normalized_trace_sqr(&self) -> Complex<T> {
+        let t = self.trace();
+        t * t / self.det()
+    }
+
+    /// Classify the transformation by its normalized trace with the tolerance `epsilon`.
+    pub fn classify(&self, epsilon: T) -> MoebiusClass {
+        let four = T::from(4).unwrap();
+        let s = self.normalized_trace_sqr();
+        if (s - four).norm() <= epsilon {
+            let [a, b, c, d]: [Complex<T>; 4] = (*self).into();
+            let off = b.norm_sqr() + c.norm_sqr() + (a - d).norm_sqr();
+            if off <= epsilon * epsilon * (a.norm_sqr() + d.norm_sqr()) {
+                MoebiusClass::Identity
+            } else {
+                MoebiusClass::Parabolic
+            }
+        } else if s.im().abs() <= epsilon && s.re() >= T::zero() {
+            if s.re() < four {
+                MoebiusClass::Elliptic
+            } else {
+                MoebiusClass::Hyperbolic
+            }
+        } else {
+            MoebiusClass::Loxodromic
+        }
+    }
+
+    /// Eigenvalues of the coefficient matrix, the first one has the greater norm.
+    fn eigenvalues(&self) -> [Complex<T>; 2] {
+        let two = T::one() + T::one();
+        let t = self.trace();
+        let r = (t * t - self.det() * (two * two)).sqrt();
+        let (l1, l2) = ((t + r) / two, (t - r) / two);
+        if l1.norm_sqr() >= l2.norm_sqr() { [l1, l2] } else { [l2, l1] }
+    }
+    /// Eigenvector of the coefficient matrix for the eigenvalue `l`, or `None` if the matrix is scalar.
+    fn eigenvector(&self, l: Complex<T>) -> Option<[Complex<T>; 2]> {
+        let [a, b, c, d]: [Complex<T>; 4] = (*self).into();
+        let (u, v) = ([b, l - a], [l - d, c]);
+        let (nu, nv) = (u[0].norm_sqr() + u[1].norm_sqr(), v[0].norm_sqr() + v[1].norm_sqr());
+        if nu.is_zero() && nv.is_zero() {
+            None
+        } else if nu >= nv {
+            Some(u)
+        } else {
+            Some(v)
+        }
+    }
+
+    /// Fixed points of the transformation.
+    ///
+    /// The first point is repelling and the second one is attracting (for loxodromic and hyperbolic transformations).
+    /// Parabolic transformation has both points equal.
+    /// For identity transformation `[∞, 0]` is returned.
+    pub fn fixed_points(&self) -> [Projective<Complex<T>>; 2] {
+        let [l1, l2] = self.eigenvalues();
+        // `[[a, b], [c, d]] * [z, 1]ᵀ = λ [z, 1]ᵀ`, so eigenvectors give the fixed points.
+        match (self.eigenvector(l1), self.eigenvector(l2)) {
+            (Some(u), Some(v)) => [point(v), point(u)],
+            _ => [Projective::Infinity, Projective::Finite(Complex::zero())],
+        }
+    }
+
+    /// Multiplier `k` of the normal form `z -> k z` with `|k| >= 1`.
+    ///
+    /// It is `1` for parabolic transformations.
+    pub fn multiplier(&self) -> Complex<T> {
+        let [l1, l2] = self.eigenvalues();
+        l1 / l2
+    }
+    /// Hyperbolic translation length `ln |k|`, where `k` is the multiplier.
+    pub fn translation_length(&self) -> T {
+        self.multiplier().norm().ln()
+    }
+
+    /// Conjugation to the normal form: returns `(g, n)` such that `self == g ∘ n ∘ g⁻¹` up to a scalar factor.
+    ///
+    /// Normal form `n` is `z -> z + 1` for parabolic transformations and `z -> k z` otherwise, where `k` is the multiplier.
+    /// Transformation is treated as parabolic if it is classified so with the tolerance `epsilon`.
+    pub fn normal_form(&self, epsilon: T) -> (Self, Self) {
+        let one = Complex::one();
+        match self.classify(epsilon) {
+            MoebiusClass::Identity => (Self::identity(), Self::identity()),
+            MoebiusClass::Parabolic => {
+                let two = T::one() + T::one();
+                let l = self.trace() / two;
+                let u = self.eigenvector(l).unwrap();
+                // Find `w` such that `(M - λ) w = λ u`, the system is consistent because `(M - λ)² = 0`.
+                let [a, b, c, d]: [Complex<T>; 4] = (*self).into();
+                let (r0, r1) = ([a - l, b], [c, d - l]);
+                let (r, y) = if r0[0].norm_sqr() + r0[1].norm_sqr() >= r1[0].norm_sqr() + r1[1].norm_sqr() {
+                    (r0, l * u[0])
+                } else {
+                    (r1, l * u[1])
+                };
+                let n = r[0].norm_sqr() + r[1].norm_sqr();
+                let w = [r[0].conj() * y / n, r[1].conj() * y / n];
+                (Self::new(u[0], w[0], u[1], w[1]), Self::new(one, one, Complex::zero(), one))
+            },
+            _ => {
+                let [l1, l2] = self.eigenvalues();
+                let (u, v) = (self.eigenvector(l1).unwrap(), self.eigenvector(l2).unwrap());
+                // Maps `0` to the repelling fixed point and `∞` to the attracting one.
+                (Self::new(u[0], v[0], u[1], v[1]), Self::new(l1 / l2, Complex::zero(), Complex::zero(), one))
+            },
+        }
+    }
+}
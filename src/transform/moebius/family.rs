@@ -0,0 +1,112 @@
+use num_traits::{Float, Zero, One};
+use crate::{*, transform::*};
+use super::*;
+
+
+/// Cross-ratio `(z1, z2; z3, z4) = ((z1 - z3)(z2 - z4)) / ((z2 - z3)(z1 - z4))`.
+///
+/// It is invariant under Moebius transformations of complex plane.
+pub fn cross_ratio<T: Algebra + Clone>(z1: Complex<T>, z2: Complex<T>, z3: Complex<T>, z4: Complex<T>) -> Complex<T> {
+    ((z1.clone() - z3.clone()) * (z2.clone() - z4.clone())) / ((z2 - z3) * (z1 - z4))
+}
+
+/// Classical transformations of complex plane.
+///
+/// The coefficients are not normalized, use [`Moebius::normalize`] if needed.
+impl<T: Float + Algebra> Moebius<Complex<T>> {
+    /// Transformation that maps `z1, z2, z3` to `0, 1, ∞` respectively, i.e. `z -> (z, z2; z1, z3)`.
+    fn to_zero_one_inf(z: [Complex<T>; 3]) -> Self {
+        let [z1, z2, z3] = z;
+        Self::new(z2 - z3, -z1 * (z2 - z3), z2 - z1, -z3 * (z2 - z1))
+    }
+    /// Unique transformation that maps distinct points `z[n]` to distinct points `w[n]`.
+    pub fn from_three_points(z: [Complex<T>; 3], w: [Complex<T>; 3]) -> Self {
+        Self::to_zero_one_inf(w).inverse().chain(Self::to_zero_one_inf(z))
+    }
+
+    /// Translation `z -> z + b`.
+    pub fn translation(b: Complex<T>) -> Self {
+        Self::new(Complex::one(), b, Complex::zero(), Complex::one())
+    }
+    /// Rotation around the origin by the `angle` in radians, `z -> e^(iθ) z`.
+    pub fn rotation(angle: T) -> Self {
+        Self::new(Complex::new(angle.cos(), angle.sin()), Complex::zero(), Complex::zero(), Complex::one())
+    }
+    /// Dilation around the origin, `z -> k z`.
+    pub fn dilation(k: T) -> Self {
+        Self::new(Complex::one() * k, Complex::zero(), Complex::zero(), Complex::one())
+    }
+    /// Inversion `z -> 1 / z`.
+    pub fn inversion() -> Self {
+        Self::new(Complex::zero(), Complex::one(), Complex::one(), Complex::zero())
+    }
+    /// Cayley transform `z -> (z - i) / (z + i)` which maps the upper half-plane onto the unit disc.
+    pub fn cayley() -> Self {
+        Self::new(Complex::one(), -Complex::i(), Complex::one(), Complex::i())
+    }
+    /// Automorphism of the unit disc `z -> e^(iθ) (z - a) / (1 - ā z)`, where `|a| < 1`.
+    pub fn disc_automorphism(angle: T, a: Complex<T>) -> Self {
+        let r = Complex::new(angle.cos(), angle.sin());
+        Self::new(r, -r * a, -a.conj(), Complex::one())
+    }
+    /// Automorphism of the upper half-plane with real coefficients, where `ad - bc > 0`.
+    pub fn from_real(a: T, b: T, c: T, d: T) -> Self {
+        let e = Complex::one();
+        Self::new(e * a, e * b, e * c, e * d)
+    }
+}
+
+/// Classical transformations of quaternions.
+///
+/// Points `x + yi + zj` with `z > 0` form the upper half-space model of 3-dimensional hyperbolic space.
+/// A transformation maps it onto itself, and then it is an isometry of hyperbolic space, only under the conditions stated for each of them.
+/// The coefficients are not normalized.
+impl<T: Float + Algebra> Moebius<Quaternion<T>> {
+    /// Translation `p -> p + b`.
+    ///
+    /// Isometry of the upper half-space for horizontal `b = x + yi`.
+    pub fn translation(b: Quaternion<T>) -> Self {
+        Self::new(Quaternion::one(), b, Quaternion::zero(), Quaternion::one())
+    }
+    /// Rotation `p -> q p q⁻¹` of the imaginary part of `p` by the quaternion `q`.
+    ///
+    /// Isometry of the upper half-space only for real `q` or `q = j` (up to the factor), use [`Self::vertical_rotation`] instead.
+    pub fn rotation(q: Quaternion<T>) -> Self {
+        Self::new(q, Quaternion::zero(), Quaternion::zero(), q)
+    }
+    /// Rotation around the vertical axis `j` by the `angle` in radians, `p -> e^(iθ/2) p e^(iθ/2)`.
+    ///
+    /// It rotates `x + yi` as a complex number and keeps `zj`, so it is an isometry of the upper half-space.
+    pub fn vertical_rotation(angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let r = Quaternion::new2(half.cos(), half.sin(), T::zero(), T::zero());
+        Self::new(r, Quaternion::zero(), Quaternion::zero(), r.conj())
+    }
+    /// Dilation around the origin, `p -> k p`.
+    ///
+    /// Isometry of the upper half-space for `k > 0`.
+    pub fn dilation(k: T) -> Self {
+        Self::new(Quaternion::one() * k, Quaternion::zero(), Quaternion::zero(), Quaternion::one())
+    }
+    /// Inversion `p -> p⁻¹`.
+    ///
+    /// It maps the upper half-space onto the lower one, see [`Self::hyperbolic_inversion`].
+    pub fn inversion() -> Self {
+        Self::new(Quaternion::zero(), Quaternion::one(), Quaternion::one(), Quaternion::zero())
+    }
+    /// Inversion `p -> -p⁻¹`, isometry of the upper half-space that swaps `0` and `∞`.
+    pub fn hyperbolic_inversion() -> Self {
+        Self::new(Quaternion::zero(), -Quaternion::one(), Quaternion::one(), Quaternion::zero())
+    }
+}
+
+/// Poincaré extension: complex transformation acts on quaternions the same way.
+///
+/// The result is an isometry of the upper half-space if the complex transformation is normalized, see [`Moebius::normalize`].
+impl<T: Float + Algebra> From<Moebius<Complex<T>>> for Moebius<Quaternion<T>> {
+    fn from(m: Moebius<Complex<T>>) -> Self {
+        let [a, b, c, d]: [Complex<T>; 4] = m.into();
+        let e = |z: Complex<T>| Quaternion::new(z, Complex::zero());
+        Self::new(e(a), e(b), e(c), e(d))
+    }
+}
@@ -0,0 +1,106 @@
+use num_traits::Float;
+use crate::{*, transform::*};
+use super::*;
+
+
+impl<T: Algebra + Clone> Inverse for Moebius<Complex<T>> {
+    /// Adjugate matrix `(d, -b, -c, a)`, which differs from the inverse matrix only by the factor `det()`.
+    fn inverse(self) -> Self {
+        let [a, b, c, d]: [Complex<T>; 4] = self.into();
+        Self::new(d, -b, -c, a)
+    }
+}
+
+/// Inverse of matrix `[[a, b], [c, d]]` using the Schur complement of `a`.
+fn schur_inverse<U: Algebra<T> + Clone, T: Algebra>(a: U, b: U, c: U, d: U) -> [U; 4] {
+    let ai = U::one() / a;
+    let ca = c * ai.clone();
+    let si = U::one() / (d - ca.clone() * b.clone());
+    let bsi = ai.clone() * b * si.clone();
+    [ai + bsi.clone() * ca.clone(), -bsi, -(si.clone() * ca), si]
+}
+
+impl<T: Float + Algebra, U: Algebra<T> + Clone> Inverse for Moebius<Construct<T, Construct<T, U>>> where Construct<T, Construct<T, U>>: Associative + DivisionAlgebra {
+    /// Inverse matrix computed by block elimination with the coefficient of the largest norm as a pivot.
+    fn inverse(self) -> Self {
+        let m: [Construct<T, Construct<T, U>>; 4] = self.into();
+        let k = pivot(&m).unwrap_or(0);
+        let [a, b, c, d] = m;
+        // Rows and columns are swapped to move the pivot to the top left corner.
+        let [p, q, r, s] = match k {
+            0 => schur_inverse(a, b, c, d),
+            1 => { let [p, q, r, s] = schur_inverse(b, a, d, c); [r, s, p, q] },
+            2 => { let [p, q, r, s] = schur_inverse(c, d, a, b); [q, p, s, r] },
+            _ => { let [p, q, r, s] = schur_inverse(d, c, b, a); [s, r, q, p] },
+        };
+        Self::new(p, q, r, s)
+    }
+}
+
+impl<U> Moebius<U> where Self: Chain<U> + Inverse + Identity + Clone {
+    /// Raise the transformation to the integer power `n` by repeated squaring.
+    ///
+    /// Negative powers are powers of the inverse transformation.
+    pub fn pow(self, n: i32) -> Self {
+        let mut base = if n < 0 { self.inverse() } else { self };
+        let mut n = n.unsigned_abs();
+        let mut acc = Self::identity();
+        while n > 0 {
+            if n & 1 == 1 {
+                acc = acc.chain(base.clone());
+            }
+            base = base.clone().chain(base);
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+/// Check that `a` is equal to `b` up to the relative tolerance `epsilon`.
+fn close<T: Float + Algebra, U: Algebra<T> + Clone>(a: [U; 4], b: [U; 4], epsilon: T) -> bool {
+    let (scale, diff) = IntoIterator::into_iter(a).zip(b).fold((T::zero(), T::zero()), |(scale, diff), (x, y)| {
+        (scale + x.clone().norm_sqr(), diff + (x - y).norm_sqr())
+    });
+    diff <= epsilon * epsilon * scale
+}
+
+/// Index of the coefficient with the largest norm.
+fn pivot<T: Float + Algebra, U: Algebra<T> + Clone>(m: &[U; 4]) -> Option<usize> {
+    let norms = m.clone().map(|x| x.norm_sqr());
+    let k = (1..4).fold(0, |k, i| if norms[i] > norms[k] { i } else { k });
+    if norms[k] > T::zero() { Some(k) } else { None }
+}
+
+impl<T: Float + Algebra> Moebius<Complex<T>> {
+    /// Check that `self` is equal to `other` multiplied by some non-zero complex factor,
+    /// i.e. both represent the same transformation.
+    ///
+    /// Coefficients are compared with the relative tolerance `epsilon`.
+    pub fn projective_eq(&self, other: &Self, epsilon: T) -> bool {
+        let (a, b): ([Complex<T>; 4], [Complex<T>; 4]) = ((*self).into(), (*other).into());
+        match pivot(&b) {
+            Some(k) => {
+                let f = a[k] / b[k];
+                close(a, b.map(|x| x * f), epsilon)
+            },
+            None => pivot(&a).is_none(),
+        }
+    }
+}
+
+impl<T: Float + Algebra + Dot<Output=T>> Moebius<Quaternion<T>> {
+    /// Check that `self` is equal to `other` multiplied by some non-zero real factor,
+    /// i.e. both represent the same transformation.
+    ///
+    /// Coefficients are compared with the relative tolerance `epsilon`.
+    pub fn projective_eq(&self, other: &Self, epsilon: T) -> bool {
+        let (a, b): ([Quaternion<T>; 4], [Quaternion<T>; 4]) = ((*self).into(), (*other).into());
+        match pivot(&b) {
+            Some(k) => {
+                let f = a[k].dot(b[k]) / b[k].norm_sqr();
+                close(a, b.map(|x| x * f), epsilon)
+            },
+            None => pivot(&a).is_none(),
+        }
+    }
+}
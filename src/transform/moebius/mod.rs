@@ -1,10 +1,24 @@
 mod base;
 pub use base::*;
 
+mod group;
+
+mod family;
+pub use family::*;
+
+mod classify;
+pub use classify::*;
+
 #[cfg(feature = "random")]
 mod random;
 #[cfg(feature = "random")]
 pub use random::*;
 
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(feature = "alloc")]
+mod batch;
+
 #[cfg(all(test, feature = "random", feature = "approx"))]
 mod tests;
@@ -7,7 +7,8 @@ use super::*;
 
 pub use rand_distr::StandardNormal;
 
-/// Distribution that produces normalized Moebius transformation, i.e. `det() == 1`.
+/// Distribution that produces normalized Moebius transformation,
+/// i.e. `det() == 1` for complex coefficients and `dieudonne_det() == 1` for higher algebras.
 pub struct Normalized;
 
 
@@ -20,16 +21,28 @@ impl<U> Distribution<Moebius<U>> for StandardNormal where StandardNormal: Distri
     }
 }
 
-impl<T: Float + Algebra, U: NormSqr<Output=T> + Clone> Distribution<Moebius<Construct<T, U>>> for Normalized where
-    StandardNormal: Distribution<Moebius<Construct<T, U>>>,
-    Construct<T, U>: Algebra<T>
-{
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Moebius<Construct<T, U>> {
+#[allow(clippy::needless_borrows_for_generic_args)]
+impl<T: Float + Algebra> Distribution<Moebius<Complex<T>>> for Normalized where StandardNormal: Distribution<Moebius<Complex<T>>> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Moebius<Complex<T>> {
         loop {
-            let m = rng.sample(&StandardNormal);
+            let m: Moebius<Complex<T>> = rng.sample(&StandardNormal);
             if m.det().norm() > T::epsilon() {
                 break m.normalize();
             }
         }
     }
 }
+#[allow(clippy::needless_borrows_for_generic_args)]
+impl<T: Float + Algebra, U: Algebra<T> + Clone> Distribution<Moebius<Construct<T, Construct<T, U>>>> for Normalized where
+    StandardNormal: Distribution<Moebius<Construct<T, Construct<T, U>>>>,
+    Construct<T, Construct<T, U>>: Associative + DivisionAlgebra,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Moebius<Construct<T, Construct<T, U>>> {
+        loop {
+            let m: Moebius<Construct<T, Construct<T, U>>> = rng.sample(&StandardNormal);
+            if m.dieudonne_det() > T::epsilon() {
+                break m.normalize();
+            }
+        }
+    }
+}
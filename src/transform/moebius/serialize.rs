@@ -0,0 +1,70 @@
+use core::{fmt::{self, Formatter}, marker::PhantomData};
+use serde::{
+    Serialize, Serializer, Deserialize, Deserializer,
+    ser::SerializeTuple,
+    de::{self, Visitor, SeqAccess},
+};
+use super::*;
+
+
+/// Serialized as tuple `[a, b, c, d]`.
+impl<U: Serialize> Serialize for Moebius<U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(4)?;
+        tuple.serialize_element(self.a_ref())?;
+        tuple.serialize_element(self.b_ref())?;
+        tuple.serialize_element(self.c_ref())?;
+        tuple.serialize_element(self.d_ref())?;
+        tuple.end()
+    }
+}
+
+struct MoebiusVisitor<U> {
+    ph: PhantomData<U>,
+}
+impl<'de, U: Deserialize<'de>> Visitor<'de> for MoebiusVisitor<U> {
+    type Value = Moebius<U>;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a sequence of 4 elements")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut next = |i| seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self));
+        let (a, b, c, d) = (next(0)?, next(1)?, next(2)?, next(3)?);
+        Ok(Moebius::new(a, b, c, d))
+    }
+}
+
+impl<'de, U: Deserialize<'de>> Deserialize<'de> for Moebius<U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(4, MoebiusVisitor { ph: PhantomData })
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{Complex, Quaternion, transform::*};
+
+    #[test]
+    fn json() {
+        let m = Moebius::new(
+            Complex::new(1.0, 2.0), Complex::new(0.0, -1.0),
+            Complex::new(0.5, 0.0), Complex::new(1.0, 0.0),
+        );
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,2.0],[0.0,-1.0],[0.5,0.0],[1.0,0.0]]");
+        assert_eq!(serde_json::from_str::<Moebius<Complex<f64>>>(&json).unwrap(), m);
+        assert!(serde_json::from_str::<Moebius<Complex<f64>>>("[[1.0,2.0],[0.0,-1.0],[0.5,0.0]]").is_err());
+    }
+
+    #[test]
+    fn binary() {
+        let m = Moebius::new(
+            Quaternion::new2(1, 2, 3, 4), Quaternion::new2(5, 6, 7, 8),
+            Quaternion::new2(-1, -2, -3, -4), Quaternion::new2(-5, -6, -7, -8),
+        );
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bytes.len(), 16 * 4);
+        assert_eq!(bincode::deserialize::<Moebius<Quaternion<i32>>>(&bytes).unwrap(), m);
+    }
+}
@@ -61,3 +61,53 @@ fn quaternion_directional_derivation() {
         );
     }
 }
+
+#[test]
+fn complex_normalize() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF5);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(StandardNormal);
+        let b = a.normalize();
+        assert_abs_diff_eq!(b.det(), Complex::one(), epsilon=1e-10);
+        assert_abs_diff_eq!(a.dieudonne_det(), a.det().norm(), epsilon=1e-10);
+        let p: Complex<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(a.apply(p), b.apply(p), epsilon=1e-8);
+
+        let c: Moebius<Complex<f64>> = rng.sample(Normalized);
+        assert_abs_diff_eq!(c.det(), Complex::one(), epsilon=1e-10);
+    }
+}
+
+#[test]
+fn quaternion_normalize() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF6);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Quaternion<f64>> = rng.sample(StandardNormal);
+        let b = a.normalize();
+        assert_abs_diff_eq!(b.dieudonne_det(), 1.0, epsilon=1e-10);
+        assert_abs_diff_eq!(b.study_det(), 1.0, epsilon=1e-10);
+        let p: Quaternion<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(a.apply(p), b.apply(p), epsilon=1e-8);
+
+        let c: Moebius<Quaternion<f64>> = rng.sample(Normalized);
+        assert_abs_diff_eq!(c.study_det(), 1.0, epsilon=1e-10);
+        let (da, dc) = (a.study_det(), c.study_det());
+        assert_abs_diff_eq!(a.chain(c).study_det(), da * dc, epsilon=1e-8 * da * dc);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn batch_apply() {
+    use alloc::vec::Vec;
+    use crate::batch::*;
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF4);
+    let m: Moebius<Complex<f64>> = rng.sample(Normalized);
+    let c: Vec<Complex<f64>> = (0..SAMPLE_ATTEMPTS).map(|_| rng.sample(StandardNormal)).collect();
+    let q: Vec<Quaternion<f64>> = (0..SAMPLE_ATTEMPTS).map(|_| rng.sample(StandardNormal)).collect();
+    let (cs, qs) = (m.apply_soa(&ComplexSoA::from(c.clone())), m.apply_soa(&QuaternionSoA::from(q.clone())));
+    for n in 0..SAMPLE_ATTEMPTS {
+        assert_abs_diff_eq!(cs.get::<Complex<f64>>(n).unwrap(), m.apply(c[n]), epsilon=1e-9);
+        assert_abs_diff_eq!(qs.get::<Quaternion<f64>>(n).unwrap(), m.apply(q[n]), epsilon=1e-9);
+    }
+}
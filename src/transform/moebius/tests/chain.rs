@@ -1,7 +1,7 @@
 use rand::{prelude::*};
 use rand_xorshift::XorShiftRng;
 use approx::*;
-use crate::{transform::*, Complex, Quaternion, Octonion};
+use crate::{transform::*, Complex, Quaternion};
 
 
 const TRANSFORM_ATTEMPTS: usize = 64;
@@ -39,20 +39,21 @@ fn moebius4() {
     }
 }
 
-/// Moebuis transform over octonions isn't chainable and therefore should fail
 #[test]
-#[should_panic]
-fn moebius8() {
+fn refs() {
     let mut rng = XorShiftRng::seed_from_u64(0xDEAD2);
     for _ in 0..TRANSFORM_ATTEMPTS {
-        let a: Moebius<Octonion<f64>> = rng.sample(&Normalized);
-        let b: Moebius<Octonion<f64>> = rng.sample(&Normalized);
-        let c = a.chain(b);
+        let a: Moebius<Quaternion<f64>> = rng.sample(Normalized);
+        let b: Moebius<Quaternion<f64>> = rng.sample(Normalized);
+        let (c, d) = (&a * &b, &a / &b);
+        let mut e = a;
+        e *= &b;
+        e /= &b;
         for _ in 0..POINT_ATTEMPTS {
-            let x: Octonion<f64> = rng.sample(&StandardNormal);
-            let y = a.apply(b.apply(x));
-            let z = c.apply(x);
-            assert_abs_diff_eq!(y, z, epsilon=1e-12);
+            let x: Quaternion<f64> = rng.sample(StandardNormal);
+            assert_abs_diff_eq!(c.apply(x), a.chain(b).apply(x), epsilon=1e-12);
+            assert_abs_diff_eq!(d.apply(b.apply(x)), a.apply(x), epsilon=1e-10);
+            assert_abs_diff_eq!(e.apply(x), a.apply(x), epsilon=1e-10);
         }
     }
 }
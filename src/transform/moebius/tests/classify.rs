@@ -0,0 +1,103 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, transform::*, Complex};
+
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+type Mc = Moebius<Complex<f64>>;
+
+/// Conjugate `n` by random transformation.
+fn conjugate(rng: &mut XorShiftRng, n: Mc) -> Mc {
+    let g: Mc = rng.sample(Normalized);
+    g.chain(n).chain(g.inverse())
+}
+
+#[test]
+fn trace() {
+    let m = Mc::new(Complex::new(1.0, 2.0), Complex::new(3.0, 0.0), Complex::new(0.0, 1.0), Complex::new(-4.0, 1.0));
+    assert_abs_diff_eq!(m.trace(), Complex::new(-3.0, 3.0));
+}
+
+#[test]
+fn classify() {
+    let mut rng = XorShiftRng::seed_from_u64(0xC1A50);
+    let one = Complex::new(1.0, 0.0);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let rot = Mc::rotation(rng.gen_range(0.1, 6.0));
+        let dil = Mc::dilation(rng.gen_range(1.5, 4.0));
+        let lox = Mc::new(Complex::new(1.5, 2.0), Complex::zero(), Complex::zero(), one);
+        assert_eq!(conjugate(&mut rng, Mc::identity()).classify(1e-8), MoebiusClass::Identity);
+        assert_eq!(conjugate(&mut rng, rot).classify(1e-8), MoebiusClass::Elliptic);
+        assert_eq!(conjugate(&mut rng, Mc::translation(one)).classify(1e-8), MoebiusClass::Parabolic);
+        assert_eq!(conjugate(&mut rng, dil).classify(1e-8), MoebiusClass::Hyperbolic);
+        assert_eq!(conjugate(&mut rng, lox).classify(1e-8), MoebiusClass::Loxodromic);
+        assert_eq!(Mc::dilation(-2.0).classify(1e-8), MoebiusClass::Loxodromic);
+    }
+}
+
+#[test]
+fn fixed_points() {
+    let mut rng = XorShiftRng::seed_from_u64(0xC1A51);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Mc = rng.sample(Normalized);
+        let [p, q] = m.fixed_points();
+        for z in [p, q].iter() {
+            assert_eq!(m.apply(*z).is_infinity(), z.is_infinity());
+            if let Projective::Finite(z) = *z {
+                assert_abs_diff_eq!(m.apply(z), z, epsilon=1e-6 * (1.0 + z.norm()));
+            }
+        }
+        if m.classify(1e-8) == MoebiusClass::Loxodromic {
+            // Iterations converge to the attracting point.
+            let mut z: Complex<f64> = rng.sample(StandardNormal);
+            for _ in 0..POINT_ATTEMPTS {
+                z = m.pow(8).apply(z);
+            }
+            let (dp, dq) = ((z - p.finite().unwrap()).norm(), (z - q.finite().unwrap()).norm());
+            assert!(dq < dp);
+        }
+    }
+}
+
+#[test]
+fn fixed_points_infinity() {
+    let m = Mc::new(Complex::new(2.0, 0.0), Complex::new(1.0, 0.0), Complex::zero(), Complex::new(1.0, 0.0));
+    let [p, q] = m.fixed_points();
+    assert_abs_diff_eq!(p.finite().unwrap(), Complex::new(-1.0, 0.0));
+    assert!(q.is_infinity());
+    let [p, q] = Mc::translation(Complex::new(1.0, 0.0)).fixed_points();
+    assert!(p.is_infinity() && q.is_infinity());
+}
+
+#[test]
+fn multiplier() {
+    let mut rng = XorShiftRng::seed_from_u64(0xC1A52);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let k = Complex::new(rng.gen_range(1.5, 3.0), rng.gen_range(-2.0, 2.0));
+        let m = conjugate(&mut rng, Mc::new(k, Complex::zero(), Complex::zero(), Complex::one()));
+        assert_abs_diff_eq!(m.multiplier(), k, epsilon=1e-8);
+        assert_abs_diff_eq!(m.translation_length(), k.norm().ln(), epsilon=1e-8);
+    }
+}
+
+#[test]
+fn normal_form() {
+    let mut rng = XorShiftRng::seed_from_u64(0xC1A53);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let ms = [
+            rng.sample(Normalized),
+            conjugate(&mut rng, Mc::translation(Complex::new(2.0, 1.0))),
+            conjugate(&mut rng, Mc::rotation(1.0)),
+        ];
+        for m in ms.iter() {
+            let (g, n) = m.normal_form(1e-8);
+            assert!(g.chain(n).chain(g.inverse()).projective_eq(m, 1e-6));
+            match m.classify(1e-8) {
+                MoebiusClass::Parabolic => assert!(n.projective_eq(&Mc::translation(Complex::one()), 1e-12)),
+                _ => assert!(n.b().is_zero() && n.c().is_zero()),
+            }
+        }
+    }
+}
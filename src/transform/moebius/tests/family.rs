@@ -0,0 +1,163 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, transform::*, Complex, Quaternion, random::Unit};
+
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+type Mc = Moebius<Complex<f64>>;
+type Mq = Moebius<Quaternion<f64>>;
+
+#[test]
+fn three_points() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D0);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let z: [Complex<f64>; 3] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        let w: [Complex<f64>; 3] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        let m = Moebius::from_three_points(z, w);
+        for i in 0..3 {
+            assert_abs_diff_eq!(m.apply(z[i]), w[i], epsilon=1e-8);
+        }
+    }
+}
+
+#[test]
+fn three_points_unique() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D1);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Normalized);
+        let z: [Complex<f64>; 3] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+        let m = Moebius::from_three_points(z, [a.apply(z[0]), a.apply(z[1]), a.apply(z[2])]);
+        assert!(m.projective_eq(&a, 1e-6));
+    }
+}
+
+#[test]
+fn cross_ratio_invariance() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D2);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Normalized);
+        let z: [Complex<f64>; 4] = [
+            rng.sample(StandardNormal), rng.sample(StandardNormal),
+            rng.sample(StandardNormal), rng.sample(StandardNormal),
+        ];
+        let w = [a.apply(z[0]), a.apply(z[1]), a.apply(z[2]), a.apply(z[3])];
+        let (cz, cw) = (cross_ratio(z[0], z[1], z[2], z[3]), cross_ratio(w[0], w[1], w[2], w[3]));
+        assert_abs_diff_eq!(cz, cw, epsilon=1e-6 * cz.norm());
+    }
+}
+
+#[test]
+fn classical() {
+    let pi = core::f64::consts::PI;
+    let z = Complex::new(0.5, -2.0);
+    let b = Complex::new(1.0, 3.0);
+    assert_abs_diff_eq!(Mc::translation(b).apply(z), z + b, epsilon=1e-12);
+    assert_abs_diff_eq!(Mc::rotation(pi / 2.0).apply(z), Complex::<f64>::i() * z, epsilon=1e-12);
+    assert_abs_diff_eq!(Mc::dilation(3.0).apply(z), z * 3.0, epsilon=1e-12);
+    assert_abs_diff_eq!(Mc::inversion().apply(z), z.inv(), epsilon=1e-12);
+}
+
+#[test]
+fn cayley() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D3);
+    let m = Mc::cayley();
+    assert_abs_diff_eq!(m.apply(Complex::i()), Complex::zero(), epsilon=1e-12);
+    for _ in 0..POINT_ATTEMPTS {
+        let z: Complex<f64> = rng.sample(StandardNormal);
+        // Upper half-plane is mapped into the unit disc and the real line onto the unit circle.
+        let h = Complex::new(z.re(), z.im().abs());
+        assert!(m.apply(h).norm() < 1.0);
+        assert_abs_diff_eq!(m.apply(Complex::new(z.re(), 0.0)).norm(), 1.0, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn disc_automorphism() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D4);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Complex<f64> = rng.sample(Unit);
+        let a = a * 0.9;
+        let m = Mc::disc_automorphism(rng.gen_range(0.0, 6.0), a);
+        assert_abs_diff_eq!(m.apply(a), Complex::zero(), epsilon=1e-12);
+        for _ in 0..POINT_ATTEMPTS {
+            let z: Complex<f64> = rng.sample(Unit);
+            assert_abs_diff_eq!(m.apply(z).norm(), 1.0, epsilon=1e-10);
+            assert!(m.apply(z * 0.5).norm() < 1.0);
+        }
+    }
+}
+
+#[test]
+fn upper_half_plane() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D5);
+    let m = Mc::from_real(2.0, 1.0, 1.0, 1.0);
+    for _ in 0..POINT_ATTEMPTS {
+        let z: Complex<f64> = rng.sample(StandardNormal);
+        let h = Complex::new(z.re(), z.im().abs());
+        assert!(m.apply(h).im() > 0.0);
+    }
+}
+
+#[test]
+fn quaternion_extension() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D6);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Normalized);
+        let b = Mq::from(a);
+        for _ in 0..POINT_ATTEMPTS {
+            let p: Quaternion<f64> = rng.sample(StandardNormal);
+            assert_abs_diff_eq!(a.apply(p), b.apply(p), epsilon=1e-10);
+        }
+    }
+}
+
+#[test]
+fn quaternion_classical() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D7);
+    let q: Quaternion<f64> = rng.sample(Unit);
+    let b: Quaternion<f64> = rng.sample(StandardNormal);
+    for _ in 0..POINT_ATTEMPTS {
+        let p: Quaternion<f64> = rng.sample(StandardNormal);
+        assert_abs_diff_eq!(Mq::translation(b).apply(p), p + b, epsilon=1e-12);
+        assert_abs_diff_eq!(Mq::rotation(q).apply(p), q * p * q.inv(), epsilon=1e-12);
+        assert_abs_diff_eq!(Mq::dilation(2.0).apply(p), p * 2.0, epsilon=1e-12);
+        assert_abs_diff_eq!(Mq::inversion().apply(p), p.inv(), epsilon=1e-12);
+    }
+}
+
+/// Distance in the upper half-space model of 3-dimensional hyperbolic space.
+fn hyperbolic_distance(p: Quaternion<f64>, q: Quaternion<f64>) -> f64 {
+    (1.0 + (p - q).norm_sqr() / (2.0 * p[2] * q[2])).acosh()
+}
+
+#[test]
+fn hyperbolic_isometries() {
+    let mut rng = XorShiftRng::seed_from_u64(0xF00D8);
+    let b = Quaternion::new2(0.5, -1.5, 0.0, 0.0);
+    let c: Moebius<Complex<f64>> = rng.sample(Normalized);
+    let isometries = [
+        Mq::translation(b),
+        Mq::vertical_rotation(0.7),
+        Mq::rotation(Quaternion::j()),
+        Mq::dilation(3.0),
+        Mq::hyperbolic_inversion(),
+        Mq::from(c),
+    ];
+    let mut upper = || {
+        let p: Quaternion<f64> = rng.sample(StandardNormal);
+        Quaternion::new2(p[0], p[1], p[2].abs(), 0.0)
+    };
+    for m in isometries.iter() {
+        for _ in 0..POINT_ATTEMPTS {
+            let (p, q) = (upper(), upper());
+            let (mp, mq) = (m.apply(p), m.apply(q));
+            assert!(mp[2] > 0.0 && mq[2] > 0.0);
+            assert_abs_diff_eq!(mp[3], 0.0, epsilon=1e-12);
+            assert_abs_diff_eq!(hyperbolic_distance(mp, mq), hyperbolic_distance(p, q), epsilon=1e-8);
+        }
+    }
+    let p = upper();
+    assert!(Mq::inversion().apply(p)[2] < 0.0);
+}
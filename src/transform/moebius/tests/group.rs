@@ -0,0 +1,88 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{transform::*, Complex, Quaternion};
+
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+
+#[test]
+fn inverse2() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFACE0);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Normalized);
+        let b = a.inverse();
+        assert!(a.chain(b).projective_eq(&Moebius::identity(), 1e-12));
+        for _ in 0..POINT_ATTEMPTS {
+            let x: Complex<f64> = rng.sample(StandardNormal);
+            assert_abs_diff_eq!(b.apply(a.apply(x)), x, epsilon=1e-8);
+        }
+    }
+}
+
+#[test]
+fn inverse4() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFACE1);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Quaternion<f64>> = rng.sample(StandardNormal);
+        let b = a.inverse();
+        assert!(a.chain(b).projective_eq(&Moebius::identity(), 1e-10));
+        assert!(b.chain(a).projective_eq(&Moebius::identity(), 1e-10));
+        for _ in 0..POINT_ATTEMPTS {
+            let x: Quaternion<f64> = rng.sample(StandardNormal);
+            assert_abs_diff_eq!(b.apply(a.apply(x)), x, epsilon=1e-8);
+        }
+    }
+}
+
+#[test]
+fn inverse4_pivot() {
+    // Zero diagonal and antidiagonal coefficients.
+    let (p, q) = (Quaternion::new2(1.0, 2.0, -1.0, 0.5), Quaternion::new2(-0.5, 0.0, 3.0, 1.0));
+    let zero = Quaternion::new2(0.0, 0.0, 0.0, 0.0);
+    for a in [Moebius::new(zero, p, q, zero), Moebius::new(p, zero, zero, q)].iter() {
+        assert!(a.chain(a.inverse()).projective_eq(&Moebius::identity(), 1e-12));
+    }
+}
+
+#[test]
+fn pow() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFACE2);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Quaternion<f64>> = rng.sample(Normalized);
+        let mut b = Moebius::identity();
+        for n in 0..8 {
+            assert!(a.pow(n).projective_eq(&b, 1e-8));
+            assert!(a.pow(-n).chain(b).projective_eq(&Moebius::identity(), 1e-8));
+            b = b.chain(a);
+        }
+    }
+}
+
+#[test]
+fn projective_eq2() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFACE3);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(StandardNormal);
+        let b: Moebius<Complex<f64>> = rng.sample(StandardNormal);
+        let f: Complex<f64> = rng.sample(StandardNormal);
+        let [p, q, r, s]: [Complex<f64>; 4] = a.into();
+        assert!(a.projective_eq(&Moebius::new(p * f, q * f, r * f, s * f), 1e-12));
+        assert!(!a.projective_eq(&b, 1e-12));
+    }
+}
+
+#[test]
+fn projective_eq4() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFACE4);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let a: Moebius<Quaternion<f64>> = rng.sample(StandardNormal);
+        let f: f64 = rng.sample(StandardNormal);
+        let g: Quaternion<f64> = rng.sample(StandardNormal);
+        let [p, q, r, s]: [Quaternion<f64>; 4] = a.into();
+        assert!(a.projective_eq(&Moebius::new(p * f, q * f, r * f, s * f), 1e-12));
+        // Multiplication by a non-real quaternion changes the transformation.
+        assert!(!a.projective_eq(&Moebius::new(g * p, g * q, g * r, g * s), 1e-12));
+    }
+}
@@ -1,2 +1,8 @@
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 mod base;
 mod chain;
+mod group;
+mod family;
+mod classify;
+mod projective;
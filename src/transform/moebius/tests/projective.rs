@@ -0,0 +1,109 @@
+use rand::{prelude::*};
+use rand_xorshift::XorShiftRng;
+use approx::*;
+use crate::{prelude::*, transform::*, Complex, Quaternion};
+
+
+const TRANSFORM_ATTEMPTS: usize = 64;
+const POINT_ATTEMPTS: usize = 16;
+type Mc = Moebius<Complex<f64>>;
+type Pc = Projective<Complex<f64>>;
+type Pq = Projective<Quaternion<f64>>;
+
+#[test]
+fn infinity() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5FE0);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Mc = rng.sample(Normalized);
+        let (a, c, d) = (m.a(), m.c(), m.d());
+        // Image of infinity.
+        let w = m.apply(Pc::Infinity).finite().unwrap();
+        assert_abs_diff_eq!(w, a / c, epsilon=1e-12);
+        // Pole is mapped to infinity.
+        let pole = Pc::Finite(-d / c);
+        let p = m.apply(pole);
+        assert!(p.is_infinity() || p.finite().unwrap().norm() > 1e12);
+        assert!(m.inverse().apply(Pc::Infinity).finite().is_some());
+    }
+    let t = Mc::translation(Complex::new(1.0, 2.0));
+    assert!(t.apply(Pc::Infinity).is_infinity());
+    assert!(Mc::inversion().apply(Pc::Finite(Complex::zero())).is_infinity());
+    assert_eq!(Mc::inversion().apply(Pc::Infinity), Pc::Finite(Complex::zero()));
+}
+
+#[test]
+fn iterate_through_pole() {
+    // `z -> -1 / z` has period 2 and maps `0` and `∞` to each other.
+    let m = Mc::new(Complex::zero(), -Complex::one(), Complex::one(), Complex::zero());
+    let mut z = Pc::Finite(Complex::zero());
+    for n in 0..8 {
+        assert_eq!(z.is_infinity(), n % 2 == 1);
+        z = m.apply(z);
+    }
+}
+
+#[test]
+fn quaternion_infinity() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5FE1);
+    for _ in 0..TRANSFORM_ATTEMPTS {
+        let m: Mc = rng.sample(Normalized);
+        let w = m.apply(Pq::Infinity);
+        assert_abs_diff_eq!(w.finite().unwrap(), Quaternion::new(m.a() / m.c(), Complex::zero()), epsilon=1e-12);
+        let q: Moebius<Quaternion<f64>> = m.into();
+        let w = q.apply(Pq::Infinity);
+        assert_abs_diff_eq!(w.finite().unwrap(), Quaternion::new(m.a() / m.c(), Complex::zero()), epsilon=1e-12);
+    }
+}
+
+#[test]
+fn stereographic_complex() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5FE2);
+    assert_eq!(Pc::Infinity.to_sphere(), [0.0, 0.0, 1.0]);
+    assert_eq!(Pc::Finite(Complex::zero()).to_sphere(), [0.0, 0.0, -1.0]);
+    assert!(Pc::from_sphere([0.0, 0.0, 1.0]).is_infinity());
+    for _ in 0..POINT_ATTEMPTS {
+        let z: Complex<f64> = rng.sample(StandardNormal);
+        let p = Pc::Finite(z).to_sphere();
+        assert_abs_diff_eq!(p.iter().map(|x| x * x).sum::<f64>(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(Pc::from_sphere(p).finite().unwrap(), z, epsilon=1e-12);
+    }
+    // Unit circle is the equator.
+    assert_abs_diff_eq!(&Pc::Finite(Complex::new(0.0, 1.0)).to_sphere()[..], &[0.0, 1.0, 0.0][..], epsilon=1e-15);
+}
+
+#[test]
+fn stereographic_quaternion() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5FE3);
+    assert_eq!(Pq::Infinity.to_sphere(), [0.0, 0.0, 0.0, 0.0, 1.0]);
+    for _ in 0..POINT_ATTEMPTS {
+        let q: Quaternion<f64> = rng.sample(StandardNormal);
+        let p = Pq::Finite(q).to_sphere();
+        assert_abs_diff_eq!(p.iter().map(|x| x * x).sum::<f64>(), 1.0, epsilon=1e-12);
+        assert_abs_diff_eq!(Pq::from_sphere(p).finite().unwrap(), q, epsilon=1e-12);
+    }
+}
+
+#[test]
+fn stereographic_quaternion_3space() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5FE4);
+    assert_eq!(Pq::Infinity.to_sphere3(), [0.0, 0.0, 0.0, 1.0]);
+    assert!(Pq::from_sphere3([0.0, 0.0, 0.0, 1.0]).is_infinity());
+    assert_eq!(Pq::Finite(Quaternion::zero()).to_sphere3(), [0.0, 0.0, 0.0, -1.0]);
+    assert_eq!(Pq::from_sphere3([0.0, 0.0, 0.0, -1.0]).finite().unwrap(), Quaternion::zero());
+    for _ in 0..POINT_ATTEMPTS {
+        let mut q: Quaternion<f64> = rng.sample(StandardNormal);
+        q[3] = 0.0;
+        let p = Pq::Finite(q).to_sphere3();
+        assert_abs_diff_eq!(p.iter().map(|x| x * x).sum::<f64>(), 1.0, epsilon=1e-12);
+        // S³ is the `x₃ = 0` section of S⁴.
+        let p4 = Pq::Finite(q).to_sphere();
+        assert_abs_diff_eq!(&p[..], &[p4[0], p4[1], p4[2], p4[4]][..], epsilon=1e-15);
+        assert_abs_diff_eq!(Pq::from_sphere3(p).finite().unwrap(), q, epsilon=1e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+fn stereographic_quaternion_3space_nonzero_k() {
+    Pq::Finite(Quaternion::new2(0.0, 0.0, 0.0, 1.0)).to_sphere3();
+}
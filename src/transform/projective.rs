@@ -0,0 +1,142 @@
+use core::ops::{Add, Mul, Div};
+use num_traits::{Float, Zero};
+use crate::{*, transform::*};
+
+
+/// Point of the one-point compactification of an algebra, e.g. the Riemann sphere for complex numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projective<U> {
+    /// Regular point.
+    Finite(U),
+    /// The point at infinity.
+    Infinity,
+}
+
+impl<U> From<U> for Projective<U> {
+    fn from(x: U) -> Self {
+        Projective::Finite(x)
+    }
+}
+
+impl<U> Projective<U> {
+    /// Whether the point is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, Projective::Infinity)
+    }
+    /// Get the regular point, `None` for the point at infinity.
+    pub fn finite(self) -> Option<U> {
+        match self {
+            Projective::Finite(x) => Some(x),
+            Projective::Infinity => None,
+        }
+    }
+}
+
+/// Moebius transformation of the extended algebra, `a c⁻¹` is the image of infinity and `-c⁻¹ d` is its preimage.
+impl<U> Transform<Projective<U>> for Moebius<U> where U: Zero + Add<Output=U> + Mul<Output=U> + Div<Output=U> + Clone {
+    fn apply(&self, x: Projective<U>) -> Projective<U> {
+        let (num, den) = match x {
+            Projective::Finite(x) => (self.a() * x.clone() + self.b(), self.c() * x + self.d()),
+            Projective::Infinity => (self.a(), self.c()),
+        };
+        if den.is_zero() {
+            Projective::Infinity
+        } else {
+            Projective::Finite(num / den)
+        }
+    }
+}
+/// Moebius transformation with coefficients of lower level, e.g. complex transformation of quaternions.
+impl<T: Algebra + Clone, U: Algebra<T> + Clone> Transform<Projective<Construct<T, Construct<T, U>>>> for Moebius<Construct<T, U>> {
+    fn apply(&self, x: Projective<Construct<T, Construct<T, U>>>) -> Projective<Construct<T, Construct<T, U>>> {
+        let (num, den) = match x {
+            Projective::Finite(x) => (self.a() * x.clone() + self.b(), self.c() * x + self.d()),
+            Projective::Infinity => (Construct::new(self.a(), Construct::zero()), Construct::new(self.c(), Construct::zero())),
+        };
+        if den.is_zero() {
+            Projective::Infinity
+        } else {
+            Projective::Finite(num / den)
+        }
+    }
+}
+
+/// Stereographic projection from the north pole of `x` onto the unit sphere, which is written to `p`.
+fn to_sphere<T: Float>(x: &[T], p: &mut [T]) {
+    let s = x.iter().fold(T::zero(), |s, &c| s + c * c);
+    let d = s + T::one();
+    for (pc, &c) in p.iter_mut().zip(x) {
+        *pc = (c + c) / d;
+    }
+    p[x.len()] = (s - T::one()) / d;
+}
+/// Inverse stereographic projection of the point `p` of the unit sphere, which is written to `x`.
+fn from_sphere<T: Float>(p: &[T], x: &mut [T]) -> bool {
+    let n = x.len();
+    let d = T::one() - p[n];
+    if d.is_zero() {
+        return false;
+    }
+    for (c, &pc) in x.iter_mut().zip(p) {
+        *c = pc / d;
+    }
+    true
+}
+
+macro_rules! impl_sphere { ($U:ident, $N:expr, $S:literal) => (
+    impl<T: Float + Algebra> Projective<$U<T>> {
+        #[doc = concat!("Stereographic projection onto the unit sphere ", $S, ", the point at infinity is mapped to the north pole `[0, ..., 0, 1]`.")]
+        pub fn to_sphere(self) -> [T; $N + 1] {
+            let mut p = [T::zero(); $N + 1];
+            match self {
+                Projective::Finite(x) => to_sphere(x.as_slice(), &mut p),
+                Projective::Infinity => p[$N] = T::one(),
+            }
+            p
+        }
+        /// Inverse stereographic projection from the unit sphere. The point is expected to be normalized.
+        pub fn from_sphere(p: [T; $N + 1]) -> Self {
+            let mut x = $U::<T>::zero();
+            if from_sphere(&p, x.as_mut_slice()) {
+                Projective::Finite(x)
+            } else {
+                Projective::Infinity
+            }
+        }
+    }
+) }
+
+impl_sphere!(Complex, 2, "S²");
+impl_sphere!(Quaternion, 4, "S⁴ (see [`to_sphere3`](Self::to_sphere3) for the points of 3-space)");
+
+impl<T: Float + Algebra> Projective<Quaternion<T>> {
+    /// Stereographic projection of a point of 3-space, i.e. with zero `k` component, onto the unit sphere S³,
+    /// the point at infinity is mapped to the north pole `[0, 0, 0, 1]`.
+    ///
+    /// 3-space `x + yi + zj` contains the upper half-space `z > 0` used by the Poincaré extension,
+    /// its boundary is the plane `z = 0` together with infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is finite and its `k` component is not zero.
+    pub fn to_sphere3(self) -> [T; 4] {
+        let mut p = [T::zero(); 4];
+        match self {
+            Projective::Finite(x) => {
+                assert!(x.as_slice()[3].is_zero(), "the point must have zero `k` component");
+                to_sphere(&x.as_slice()[..3], &mut p)
+            }
+            Projective::Infinity => p[3] = T::one(),
+        }
+        p
+    }
+    /// Inverse stereographic projection from the unit sphere S³ to 3-space. The point is expected to be normalized.
+    pub fn from_sphere3(p: [T; 4]) -> Self {
+        let mut x = Quaternion::<T>::zero();
+        if from_sphere(&p, &mut x.as_mut_slice()[..3]) {
+            Projective::Finite(x)
+        } else {
+            Projective::Infinity
+        }
+    }
+}
@@ -13,6 +13,12 @@ pub trait Identity {
     fn identity() -> Self;
 }
 
+/// Transformation that has an inverse element.
+pub trait Inverse {
+    /// Get an inverse transformation, i.e. `x.inverse().apply(x.apply(p)) == p`.
+    fn inverse(self) -> Self;
+}
+
 /// Transformation which instances could be chained into another one (i.e. forms a magma).
 pub trait Chain<U>: Transform<U> {
     fn chain(self, other: Self) -> Self;